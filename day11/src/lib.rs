@@ -1,78 +1,34 @@
-use helpers::{Coord, Grid};
+use helpers::{Automaton, Grid, Neighborhood};
 
 pub fn part1(input: Grid<usize>) -> usize {
-    let mut grid: Grid<usize> = input;
+    let mut octopuses = octopuses(input);
     let mut flashes = 0;
 
     for _ in 0..100 {
-        flashes += step(&mut grid);
+        octopuses.step();
+        flashes += octopuses.fired().len();
     }
 
     flashes
 }
 
 pub fn part2(input: Grid<usize>) -> usize {
-    let mut grid: Grid<usize> = input;
-    let mut steps = 0;
+    let mut octopuses = octopuses(input);
 
-    loop {
-        let flashes = step(&mut grid);
-        steps += 1;
-
-        if flashes == grid.len() {
-            return steps;
-        }
-    }
+    octopuses.run_until(|octopuses| octopuses.fired().len() == octopuses.grid().len())
 }
 
-fn step(grid: &mut Grid<usize>) -> usize {
-    grid.iter_mut().for_each(|(_, energy)| *energy += 1);
-
-    apply_flashes(grid);
-
-    let flashed: Vec<(Coord, usize)> = grid
-        .iter()
-        .filter(|(_, &energy)| energy > 9)
-        .map(|(coord, energy)| (coord, energy.to_owned()))
-        .collect();
-    let flashes = flashed.len();
-
-    for (coord, _) in flashed {
-        grid.set(coord, 0);
-    }
-
-    flashes
-}
-
-fn apply_flashes(grid: &mut Grid<usize>) {
-    let will_flash: Vec<(Coord, usize)> = grid
-        .iter()
-        .filter(|(_, &energy)| energy > 9 && energy < 100)
-        .map(|(coord, energy)| (coord, energy.to_owned()))
-        .collect();
-
-    if will_flash.len() == 0 {
-        return;
-    }
-
-    for (coord, _) in will_flash {
-        for (neighbor_coord, energy) in grid
-            .neighbors_iter(coord, true)
-            .map(|(coord, &energy)| (coord, energy.to_owned()))
-            .collect::<Vec<(Coord, usize)>>()
-        {
-            grid.set(neighbor_coord, energy + 1);
-        }
-
-        grid.set(coord, 100);
-    }
-
-    return apply_flashes(grid);
+fn octopuses(grid: Grid<usize>) -> Automaton<usize> {
+    Automaton::new(grid, Neighborhood::Moore, |_, &energy, _| energy + 1).with_cascade(
+        |&energy| energy > 9,
+        |energy| *energy += 1,
+        |energy| *energy = 0,
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use helpers::{input_grid, Grid};
+    use helpers::{assert_grid_eq, input_grid, Grid};
 
     use super::*;
 
@@ -100,4 +56,21 @@ mod tests {
     fn test_part2() {
         assert_eq!(part2(input()), 195)
     }
+
+    #[test]
+    fn test_step() {
+        let mut octopuses = octopuses(input_grid("11111\n19991\n19191\n19991\n11111"));
+
+        octopuses.step();
+        assert_grid_eq!(
+            octopuses.grid(),
+            input_grid::<usize>("34543\n40004\n50005\n40004\n34543")
+        );
+
+        octopuses.step();
+        assert_grid_eq!(
+            octopuses.grid(),
+            input_grid::<usize>("45654\n51115\n61116\n51115\n45654")
+        );
+    }
 }
@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
+use helpers::{ocr_points, sections};
+
 type Point = (usize, usize);
 type Fold = (char, usize);
 
-pub fn part1(input: &Vec<&str>) -> usize {
+pub fn part1(input: &str) -> usize {
     let (mut map, folds) = parse_input(input);
 
     map = do_fold(&map, folds.first().unwrap());
@@ -11,47 +13,34 @@ pub fn part1(input: &Vec<&str>) -> usize {
     map.len()
 }
 
-pub fn part2(input: &Vec<&str>) -> usize {
+pub fn part2(input: &str) -> String {
     let (mut map, folds) = parse_input(input);
 
     for fold in folds {
         map = do_fold(&map, &fold);
     }
 
-    for i in 0..6 {
-        for j in 0..40 {
-            if map.contains(&(j, i)) {
-                print!("█");
-            } else {
-                print!(" ");
-            }
-        }
-
-        println!();
-    }
-
-    0
+    ocr_points(map).unwrap()
 }
 
-fn parse_input(input: &Vec<&str>) -> (HashSet<Point>, Vec<Fold>) {
-    let mut map: HashSet<Point> = HashSet::new();
-    let mut folds: Vec<Fold> = Vec::new();
-    let mut points_read = false;
-
-    for line in input {
-        if line.starts_with("fold") {
-            points_read = true;
-        }
+fn parse_input(input: &str) -> (HashSet<Point>, Vec<Fold>) {
+    let (points, folds): (&str, &str) = sections(input).unwrap();
 
-        if !points_read {
+    let map = points
+        .lines()
+        .map(|line| {
             let split: Vec<usize> = line.split(",").map(|item| item.parse().unwrap()).collect();
-            map.insert((split[0], split[1]));
-        } else {
-            let split: Vec<&str> = line.split("=").collect();
+            (split[0], split[1])
+        })
+        .collect();
 
-            folds.push((split[0].chars().last().unwrap(), split[1].parse().unwrap()));
-        }
-    }
+    let folds = folds
+        .lines()
+        .map(|line| {
+            let split: Vec<&str> = line.split("=").collect();
+            (split[0].chars().last().unwrap(), split[1].parse().unwrap())
+        })
+        .collect();
 
     (map, folds)
 }
@@ -76,12 +65,12 @@ fn do_fold(map: &HashSet<Point>, fold: &Fold) -> HashSet<Point> {
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
+    use helpers::OcrError;
 
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
-        let input = "\
+    fn input<'a>() -> &'a str {
+        "\
 6,10
 0,14
 9,10
@@ -102,12 +91,59 @@ mod tests {
 9,0
 
 fold along y=7
-fold along x=5";
-        input_lines(input)
+fold along x=5"
+    }
+
+    // Folds into "HI", the example above only gives a square
+    fn letters_input<'a>() -> &'a str {
+        "\
+0,0
+3,12
+11,0
+10,12
+7,0
+0,11
+13,1
+10,11
+0,2
+1,10
+14,2
+13,10
+6,2
+0,9
+13,3
+10,9
+0,4
+3,8
+10,4
+16,7
+3,5
+5,7
+10,5
+9,7
+
+fold along y=6
+fold along x=8"
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 17)
+        assert_eq!(part1(input()), 17)
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(letters_input()), "HI")
+    }
+
+    #[test]
+    fn test_unrecognized_glyph() {
+        let (map, folds) = parse_input("0,0\n0,1\n0,2\n0,9\n0,8\n0,7\n\nfold along y=6");
+        let map = do_fold(&map, &folds[0]);
+
+        assert_eq!(
+            ocr_points(map),
+            Err(OcrError::UnrecognizedGlyphs([(0, 0)].to_vec()))
+        )
     }
 }
@@ -1,18 +1,20 @@
 use std::collections::HashMap;
 
-pub fn part1(input: &Vec<&str>) -> u64 {
+use helpers::sections;
+
+pub fn part1(input: &str) -> u64 {
     solve(input, 10)
 }
 
-pub fn part2(input: &Vec<&str>) -> u64 {
+pub fn part2(input: &str) -> u64 {
     solve(input, 40)
 }
 
-pub fn solve(input: &Vec<&str>, steps: usize) -> u64 {
-    let template = input[0];
+pub fn solve(input: &str, steps: usize) -> u64 {
+    let (template, rule_lines): (&str, &str) = sections(input).unwrap();
     let mut rules = HashMap::new();
 
-    for line in input.iter().skip(1) {
+    for line in rule_lines.lines() {
         let mut split = line.split(" -> ");
         rules.insert(split.next().unwrap(), split.next().unwrap());
     }
@@ -60,12 +62,10 @@ pub fn solve(input: &Vec<&str>, steps: usize) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
-        let input = "\
+    fn input<'a>() -> &'a str {
+        "\
 NNCB
 
 CH -> B
@@ -83,17 +83,16 @@ BN -> B
 BB -> N
 BC -> B
 CC -> N
-CN -> C";
-        input_lines(input)
+CN -> C"
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 1588)
+        assert_eq!(part1(input()), 1588)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 2188189693529)
+        assert_eq!(part2(input()), 2188189693529)
     }
 }
@@ -1,8 +1,8 @@
-use helpers::{input_lines, run};
+use helpers::run;
 
 fn main() {
-    let input: Vec<&str> = input_lines(include_str!("../input.txt"));
+    let input = include_str!("../input.txt");
 
-    run("part1", || day14::part1(&input));
-    run("part2", || day14::part2(&input));
+    run("part1", || day14::part1(input));
+    run("part2", || day14::part2(input));
 }
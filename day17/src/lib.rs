@@ -1,4 +1,4 @@
-use regex::Regex;
+use helpers::scan;
 
 pub fn part1(input: &str) -> isize {
     let (_, p2) = parse(input);
@@ -52,16 +52,15 @@ struct Coord {
 }
 
 fn parse(input: &str) -> (Coord, Coord) {
-    let regex = Regex::new(
-        r"^target area: x=(?P<x1>-?\d+)\.\.(?P<x2>-?\d+), y=(?P<y2>-?\d+)\.\.(?P<y1>-?\d+)$",
+    let (x1, x2, y2, y1) = scan!(
+        input.trim(),
+        "target area: x={}..{}, y={}..{}",
+        isize,
+        isize,
+        isize,
+        isize
     )
     .unwrap();
-    let captures = regex.captures(input).unwrap();
-
-    let x1: isize = captures.name("x1").unwrap().as_str().parse().unwrap();
-    let x2: isize = captures.name("x2").unwrap().as_str().parse().unwrap();
-    let y1: isize = captures.name("y1").unwrap().as_str().parse().unwrap();
-    let y2: isize = captures.name("y2").unwrap().as_str().parse().unwrap();
 
     (Coord { x: x1, y: y1 }, Coord { x: x2, y: y2 })
 }
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use helpers::scan;
+
 enum Command {
     Forward(usize),
     Down(usize),
@@ -10,12 +12,9 @@ impl FromStr for Command {
     type Err = ();
 
     fn from_str(input: &str) -> Result<Command, Self::Err> {
-        let mut split = input.split(' ');
-
-        let command = split.next().unwrap();
-        let amount = split.next().unwrap().parse().unwrap();
+        let (command, amount) = scan!(input, "{} {}", String, usize).map_err(|_| ())?;
 
-        match command {
+        match command.as_str() {
             "forward" => Ok(Command::Forward(amount)),
             "down" => Ok(Command::Down(amount)),
             "up" => Ok(Command::Up(amount)),
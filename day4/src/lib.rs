@@ -1,12 +1,10 @@
 use std::fmt::{Debug, Formatter};
-use std::str::FromStr;
 
 // use helpers::debug;
-use regex::Regex;
+use helpers::{sections, Grid};
 
-pub fn part1(input: &Vec<&str>) -> usize {
-    let numbers = parse_numbers(input);
-    let mut boards = parse_boards(input);
+pub fn part1(input: &str) -> usize {
+    let (numbers, mut boards) = parse_input(input);
     let mut winner: Option<Board> = None;
     let mut last_number = 0;
 
@@ -33,9 +31,8 @@ pub fn part1(input: &Vec<&str>) -> usize {
     winner.score(last_number)
 }
 
-pub fn part2(input: &Vec<&str>) -> usize {
-    let numbers = parse_numbers(input);
-    let mut boards = parse_boards(input);
+pub fn part2(input: &str) -> usize {
+    let (numbers, mut boards) = parse_input(input);
     let mut loser: Option<Board> = None;
     let mut last_number = 0;
     let mut completed_boards: Vec<usize> = Vec::new();
@@ -71,17 +68,10 @@ pub fn part2(input: &Vec<&str>) -> usize {
     loser.score(last_number)
 }
 
-fn parse_numbers(input: &Vec<&str>) -> Vec<usize> {
-    input[0]
-        .split(",")
-        .map(|number| number.parse().unwrap())
-        .collect()
-}
+fn parse_input(input: &str) -> (Vec<usize>, Vec<Board>) {
+    let (numbers, boards): (Vec<usize>, Vec<Grid<usize>>) = sections(input).unwrap();
 
-fn parse_boards(input: &Vec<&str>) -> Vec<Board> {
-    return (1..input.len())
-        .map(|i| Board::from_str(input[i]).unwrap())
-        .collect();
+    (numbers, boards.into_iter().map(Board::new).collect())
 }
 
 #[derive(Clone)]
@@ -92,13 +82,23 @@ struct Number {
 
 #[derive(Clone)]
 struct Board {
-    numbers: Vec<Number>,
+    numbers: Grid<Number>,
     marked_counter: usize,
 }
 
 impl Board {
+    fn new(numbers: Grid<usize>) -> Self {
+        Board {
+            numbers: Grid::from_fn(numbers.size, |coord| Number {
+                number: *numbers.get(coord),
+                marked: false,
+            }),
+            marked_counter: 0,
+        }
+    }
+
     fn mark(&mut self, number: usize) {
-        for num in self.numbers.iter_mut() {
+        for (_, num) in self.numbers.iter_mut() {
             if num.number == number {
                 num.marked = true;
                 self.marked_counter += 1;
@@ -112,60 +112,17 @@ impl Board {
             return false;
         }
 
-        (self.numbers[0].marked
-            && self.numbers[1].marked
-            && self.numbers[2].marked
-            && self.numbers[3].marked
-            && self.numbers[4].marked)
-            || (self.numbers[5].marked
-                && self.numbers[6].marked
-                && self.numbers[7].marked
-                && self.numbers[8].marked
-                && self.numbers[9].marked)
-            || (self.numbers[10].marked
-                && self.numbers[11].marked
-                && self.numbers[12].marked
-                && self.numbers[13].marked
-                && self.numbers[14].marked)
-            || (self.numbers[15].marked
-                && self.numbers[16].marked
-                && self.numbers[17].marked
-                && self.numbers[18].marked
-                && self.numbers[19].marked)
-            || (self.numbers[20].marked
-                && self.numbers[21].marked
-                && self.numbers[22].marked
-                && self.numbers[23].marked
-                && self.numbers[24].marked)
-            || (self.numbers[0].marked
-                && self.numbers[5].marked
-                && self.numbers[10].marked
-                && self.numbers[15].marked
-                && self.numbers[20].marked)
-            || (self.numbers[1].marked
-                && self.numbers[6].marked
-                && self.numbers[11].marked
-                && self.numbers[16].marked
-                && self.numbers[21].marked)
-            || (self.numbers[2].marked
-                && self.numbers[7].marked
-                && self.numbers[12].marked
-                && self.numbers[17].marked
-                && self.numbers[22].marked)
-            || (self.numbers[3].marked
-                && self.numbers[8].marked
-                && self.numbers[13].marked
-                && self.numbers[18].marked
-                && self.numbers[23].marked)
-            || (self.numbers[4].marked
-                && self.numbers[9].marked
-                && self.numbers[14].marked
-                && self.numbers[19].marked
-                && self.numbers[24].marked)
+        self.numbers
+            .rows()
+            .any(|row| row.iter().all(|number| number.marked))
+            || self
+                .numbers
+                .columns()
+                .any(|mut column| column.all(|number| number.marked))
     }
 
     fn score(&self, last_number: usize) -> usize {
-        self.numbers.iter().fold(0, |acc, number| {
+        self.numbers.iter().fold(0, |acc, (_, number)| {
             if !number.marked {
                 acc + number.number
             } else {
@@ -175,38 +132,20 @@ impl Board {
     }
 }
 
-impl FromStr for Board {
-    type Err = ();
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        Ok(Board {
-            numbers: Regex::new(r"\s+")
-                .unwrap()
-                .split(input)
-                .map(|number| Number {
-                    number: number.parse().unwrap(),
-                    marked: false,
-                })
-                .collect(),
-            marked_counter: 0,
-        })
-    }
-}
-
 impl Debug for Board {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "\n")?;
 
-        for (index, number) in self.numbers.iter().enumerate() {
-            if number.marked {
-                write!(f, "({}) ", number.number)?;
-            } else {
-                write!(f, " {}  ", number.number)?;
+        for row in self.numbers.rows() {
+            for number in row {
+                if number.marked {
+                    write!(f, "({}) ", number.number)?;
+                } else {
+                    write!(f, " {}  ", number.number)?;
+                }
             }
 
-            if 0 == (index + 1) % 5 {
-                write!(f, "\n")?;
-            }
+            write!(f, "\n")?;
         }
 
         Ok(())
@@ -215,12 +154,10 @@ impl Debug for Board {
 
 #[cfg(test)]
 mod tests {
-    use helpers::split_input;
-
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
-        let input = "\
+    fn input<'a>() -> &'a str {
+        "\
 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
 22 13 17 11  0
@@ -240,17 +177,16 @@ mod tests {
 18  8 23 26 20
 22 11 13  6  5
  2  0 12  3  7
- ";
-        split_input(input, "\n\n")
+ "
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 4512)
+        assert_eq!(part1(input()), 4512)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 1924)
+        assert_eq!(part2(input()), 1924)
     }
 }
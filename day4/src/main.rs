@@ -1,8 +1,8 @@
-use helpers::{run, split_input};
+use helpers::run;
 
 fn main() {
-    let input: Vec<&str> = split_input(include_str!("../input.txt"), "\n\n");
+    let input = include_str!("../input.txt");
 
-    run("part1", || day4::part1(&input));
-    run("part2", || day4::part2(&input));
+    run("part1", || day4::part1(input));
+    run("part2", || day4::part2(input));
 }
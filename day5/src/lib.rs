@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use regex::Regex;
+use helpers::{line, scan, SignedCoord};
 
 pub fn part1(input: &Vec<&str>) -> usize {
     solve(input, false)
@@ -11,11 +11,17 @@ pub fn part2(input: &Vec<&str>) -> usize {
 }
 
 fn solve(input: &Vec<&str>, with_diagonals: bool) -> usize {
-    let segments = parse_input(input, with_diagonals);
-    let mut points: HashMap<Point, usize> = HashMap::new();
+    let segments = parse_input(input);
+    let mut points: HashMap<SignedCoord, usize> = HashMap::new();
 
-    for segment in segments {
-        for point in segment {
+    for (p1, p2) in segments {
+        let is_straight = p1.0 == p2.0 || p1.1 == p2.1;
+
+        if !is_straight && !with_diagonals {
+            continue;
+        }
+
+        for point in line(p1, p2) {
             points
                 .entry(point)
                 .and_modify(|entry| *entry += 1)
@@ -28,115 +34,16 @@ fn solve(input: &Vec<&str>, with_diagonals: bool) -> usize {
         .fold(0, |acc, count| if *count > 1usize { acc + 1 } else { acc })
 }
 
-fn parse_input(input: &Vec<&str>, with_diagonals: bool) -> Vec<Segment> {
-    let mut segments = Vec::new();
-    let regex = Regex::new(r"(?P<x1>\d+),(?P<y1>\d+) -> (?P<x2>\d+),(?P<y2>\d+)").unwrap();
-
-    for line in input {
-        let captures = regex.captures(line).unwrap();
-
-        segments.push(Segment::new(
-            Point {
-                x: captures.name("x1").unwrap().as_str().parse().unwrap(),
-                y: captures.name("y1").unwrap().as_str().parse().unwrap(),
-            },
-            Point {
-                x: captures.name("x2").unwrap().as_str().parse().unwrap(),
-                y: captures.name("y2").unwrap().as_str().parse().unwrap(),
-            },
-            with_diagonals,
-        ))
-    }
-
-    segments
-}
-
-#[derive(Debug, Clone)]
-struct Segment {
-    p1: Point,
-    p2: Point,
-    increment: Option<Point>,
-}
-
-impl Segment {
-    fn new(p1: Point, p2: Point, with_diagonals: bool) -> Self {
-        let is_horizontal = p1.y == p2.y;
-        let is_vertical = !is_horizontal && p1.x == p2.x;
-        let is_diagonal = !is_vertical
-            && (p1.x as isize - p2.x as isize).abs() == (p1.y as isize - p2.y as isize).abs();
-
-        let increment = if is_horizontal {
-            Some(Point {
-                x: if p1.x < p2.x { 1 } else { -1 },
-                y: 0,
-            })
-        } else if is_vertical {
-            Some(Point {
-                x: 0,
-                y: if p1.y < p2.y { 1 } else { -1 },
-            })
-        } else if with_diagonals && is_diagonal {
-            Some(Point {
-                x: if p1.x < p2.x { 1 } else { -1 },
-                y: if p1.y < p2.y { 1 } else { -1 },
-            })
-        } else {
-            None
-        };
-
-        Segment { p1, p2, increment }
-    }
-}
-
-impl IntoIterator for Segment {
-    type Item = Point;
-    type IntoIter = SegmentIterator;
-
-    fn into_iter(self) -> Self::IntoIter {
-        SegmentIterator {
-            segment: self,
-            index: 0,
-            finished: false,
-        }
-    }
-}
-
-struct SegmentIterator {
-    segment: Segment,
-    index: isize,
-    finished: bool,
-}
-
-impl Iterator for SegmentIterator {
-    type Item = Point;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.finished || self.segment.increment.is_none() {
-            self.finished = true;
-
-            return None;
-        }
-
-        let increment = self.segment.increment.as_ref().unwrap();
-        let point = Point {
-            x: self.segment.p1.x + self.index * increment.x,
-            y: self.segment.p1.y + self.index * increment.y,
-        };
-
-        if point.x == self.segment.p2.x && point.y == self.segment.p2.y {
-            self.finished = true;
-        }
-
-        self.index += 1;
-
-        Some(point)
-    }
-}
+fn parse_input(input: &Vec<&str>) -> Vec<(SignedCoord, SignedCoord)> {
+    input
+        .iter()
+        .map(|line| {
+            let (x1, y1, x2, y2) =
+                scan!(line, "{},{} -> {},{}", isize, isize, isize, isize).unwrap();
 
-#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
-struct Point {
-    x: isize,
-    y: isize,
+            ((x1, y1), (x2, y2))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -9,7 +9,7 @@ pub fn part1(input: &Grid<usize>) -> usize {
 pub fn part2(input: &Grid<usize>) -> usize {
     let mut basins: Vec<usize> = get_low_points(input)
         .iter()
-        .map(|low_point| get_basin_coords(&input, low_point.0, low_point.1).len())
+        .map(|&(_, coord)| input.flood_fill(coord, |_, &height| height < 9).len())
         .collect();
 
     basins.sort_unstable();
@@ -23,7 +23,7 @@ fn get_low_points(grid: &Grid<usize>) -> Vec<(usize, Coord)> {
 
     for x in 0..grid.size.0 {
         'outer: for y in 0..grid.size.1 {
-            let &current_height = grid.get((x, y));
+            let current_height = grid[(x, y)];
 
             for (_, &neighbor_height) in grid.neighbors_iter((x, y), false) {
                 if neighbor_height <= current_height {
@@ -38,21 +38,6 @@ fn get_low_points(grid: &Grid<usize>) -> Vec<(usize, Coord)> {
     low_points
 }
 
-fn get_basin_coords(grid: &Grid<usize>, current_height: usize, coord: Coord) -> Vec<Coord> {
-    let mut positions: Vec<Coord> = vec![coord];
-
-    for (neighbor_coord, &neighbor_height) in grid.neighbors_iter(coord, false) {
-        if neighbor_height < 9 && neighbor_height > current_height {
-            positions.append(&mut get_basin_coords(grid, neighbor_height, neighbor_coord));
-        }
-    }
-
-    positions.sort_unstable();
-    positions.dedup();
-
-    positions
-}
-
 #[cfg(test)]
 mod tests {
     use helpers::input_grid;
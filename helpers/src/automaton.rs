@@ -0,0 +1,254 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::mem;
+
+use crate::{Coord, Edges, Grid, NeighborCoordIter, NeighborIter, Neighborhood};
+
+type Rule<T> = Box<dyn Fn(Coord, &T, NeighborIter<'_, T>) -> T>;
+
+struct Cascade<T> {
+    fires: Box<dyn Fn(&T) -> bool>,
+    propagate: Box<dyn Fn(&mut T)>,
+    settle: Box<dyn Fn(&mut T)>,
+}
+
+// Cellular automaton stepping every cell at once from the previous generation.
+// An optional cascade phase runs after each step: cells matching `fires` fire once, every
+// neighbor of a fired cell is updated with `propagate` (possibly firing in turn) and finally
+// every fired cell is updated with `settle`.
+pub struct Automaton<T> {
+    grid: Grid<T>,
+    buffer: Grid<T>,
+    neighborhood: Neighborhood<'static>,
+    edges: Edges,
+    rule: Rule<T>,
+    cascade: Option<Cascade<T>>,
+    fired: Vec<Coord>,
+    steps: usize,
+}
+
+impl<T: Clone> Automaton<T> {
+    pub fn new<R>(grid: Grid<T>, neighborhood: Neighborhood<'static>, rule: R) -> Self
+    where
+        R: Fn(Coord, &T, NeighborIter<'_, T>) -> T + 'static,
+    {
+        Automaton {
+            buffer: grid.clone(),
+            grid,
+            neighborhood,
+            edges: Edges::Bounded,
+            rule: Box::new(rule),
+            cascade: None,
+            fired: Vec::new(),
+            steps: 0,
+        }
+    }
+
+    pub fn with_edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    pub fn with_cascade<F, P, S>(mut self, fires: F, propagate: P, settle: S) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+        P: Fn(&mut T) + 'static,
+        S: Fn(&mut T) + 'static,
+    {
+        self.cascade = Some(Cascade {
+            fires: Box::new(fires),
+            propagate: Box::new(propagate),
+            settle: Box::new(settle),
+        });
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    // Cells fired during the cascade phase of the last step
+    pub fn fired(&self) -> &[Coord] {
+        &self.fired
+    }
+
+    pub fn step(&mut self) {
+        for (coord, cell) in self.buffer.iter_mut() {
+            *cell = (self.rule)(
+                coord,
+                self.grid.get(coord),
+                self.grid.neighbors(coord, self.neighborhood, self.edges),
+            );
+        }
+
+        mem::swap(&mut self.grid, &mut self.buffer);
+
+        self.fired.clear();
+        if self.cascade.is_some() {
+            self.run_cascade();
+        }
+
+        self.steps += 1;
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    // Steps until `predicate` holds after a step, returns the total number of steps
+    pub fn run_until<P>(&mut self, mut predicate: P) -> usize
+    where
+        P: FnMut(&Self) -> bool,
+    {
+        loop {
+            self.step();
+
+            if predicate(self) {
+                return self.steps;
+            }
+        }
+    }
+
+    // Steps until a step leaves the grid unchanged, returns the total number of steps
+    pub fn run_until_stable(&mut self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.run_until(|automaton| automaton.grid == automaton.buffer)
+    }
+
+    // Steps until a previous generation repeats, returns `(first step of the cycle, cycle length)`
+    pub fn run_until_cycle(&mut self) -> (usize, usize)
+    where
+        T: Eq + Hash,
+    {
+        let mut seen: HashMap<Grid<T>, usize> = HashMap::new();
+        seen.insert(self.grid.clone(), self.steps);
+
+        loop {
+            self.step();
+
+            if let Some(&start) = seen.get(&self.grid) {
+                return (start, self.steps - start);
+            }
+
+            seen.insert(self.grid.clone(), self.steps);
+        }
+    }
+
+    fn run_cascade(&mut self) {
+        let cascade = self.cascade.as_ref().unwrap();
+        let mut fired = vec![false; self.grid.len()];
+        let mut queue: VecDeque<Coord> = VecDeque::new();
+
+        for (coord, cell) in self.grid.iter() {
+            if (cascade.fires)(cell) {
+                fired[self.grid.index(coord)] = true;
+                queue.push_back(coord);
+            }
+        }
+
+        while let Some(coord) = queue.pop_front() {
+            self.fired.push(coord);
+
+            let neighbors =
+                NeighborCoordIter::new(self.grid.size, coord, self.neighborhood, self.edges);
+
+            for neighbor in neighbors {
+                let index = self.grid.index(neighbor);
+                let cell = self.grid.get_mut(neighbor);
+
+                (cascade.propagate)(cell);
+
+                if !fired[index] && (cascade.fires)(cell) {
+                    fired[index] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        for &coord in self.fired.iter() {
+            (cascade.settle)(self.grid.get_mut(coord));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_blinker() -> Grid<bool> {
+        Grid::new(
+            (5, 5),
+            [
+                false, false, false, false, false, //
+                false, false, true, false, false, //
+                false, false, true, false, false, //
+                false, false, true, false, false, //
+                false, false, false, false, false, //
+            ]
+            .to_vec(),
+        )
+    }
+
+    fn life(_: Coord, &alive: &bool, neighbors: NeighborIter<'_, bool>) -> bool {
+        let count = neighbors.filter(|(_, &neighbor)| neighbor).count();
+
+        count == 3 || (alive && count == 2)
+    }
+
+    #[test]
+    fn test_step_is_synchronous() {
+        let mut automaton = Automaton::new(create_blinker(), Neighborhood::Moore, life);
+
+        automaton.step();
+
+        assert_eq!(automaton.grid(), &create_blinker().rotate_cw());
+        assert_eq!(automaton.steps(), 1);
+    }
+
+    #[test]
+    fn test_run_until_cycle() {
+        let mut automaton = Automaton::new(create_blinker(), Neighborhood::Moore, life);
+
+        assert_eq!(automaton.run_until_cycle(), (0, 2));
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        let grid = Grid::new((4, 1), [0, 3, 0, 0].to_vec());
+        let mut automaton =
+            Automaton::new(grid, Neighborhood::VonNeumann, |_, &cell, neighbors| {
+                neighbors.map(|(_, &n)| n).fold(cell, usize::max)
+            });
+
+        assert_eq!(automaton.run_until_stable(), 3);
+        assert_eq!(automaton.grid(), &Grid::new((4, 1), [3, 3, 3, 3].to_vec()));
+    }
+
+    #[test]
+    fn test_cascade() {
+        let grid = Grid::new((3, 1), [9, 8, 1].to_vec());
+        let mut automaton = Automaton::new(grid, Neighborhood::VonNeumann, |_, &cell, _| cell + 1)
+            .with_cascade(|&cell| cell > 9, |cell| *cell += 1, |cell| *cell = 0);
+
+        automaton.step();
+
+        assert_eq!(automaton.fired(), &[(0, 0), (1, 0)]);
+        assert_eq!(automaton.grid(), &Grid::new((3, 1), [0, 0, 3].to_vec()));
+    }
+}
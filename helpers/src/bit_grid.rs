@@ -0,0 +1,387 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::{Coord, Grid};
+
+const WORD_BITS: usize = 64;
+
+// Boolean grid packed in `u64` words, every row starts on a new word and the unused bits at the
+// end of a row are always zero.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    pub size: (usize, usize),
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(size: (usize, usize)) -> Self {
+        let words_per_row = size.0.div_ceil(WORD_BITS);
+
+        BitGrid {
+            size,
+            words_per_row,
+            words: vec![0; words_per_row * size.1],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size.0 * self.size.1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        0 == self.len()
+    }
+
+    pub fn get(&self, coord: Coord) -> bool {
+        let (word, bit) = self.position(coord);
+
+        self.words[word] & (1 << bit) != 0
+    }
+
+    pub fn set(&mut self, coord: Coord, value: bool) {
+        let (word, bit) = self.position(coord);
+
+        if value {
+            self.words[word] |= 1 << bit;
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+    }
+
+    pub fn toggle(&mut self, coord: Coord) {
+        let (word, bit) = self.position(coord);
+
+        self.words[word] ^= 1 << bit;
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn iter_ones(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let y = index / self.words_per_row;
+                let x_offset = (index % self.words_per_row) * WORD_BITS;
+
+                BitIter(word).map(move |bit| (x_offset + bit, y))
+            })
+    }
+
+    // Moves every cell `n` columns to the right, cells pushed past the edge are dropped
+    pub fn shift_right(&self, n: usize) -> BitGrid {
+        let mut shifted = BitGrid::new(self.size);
+        let (word_shift, bit_shift) = (n / WORD_BITS, n % WORD_BITS);
+
+        for y in 0..self.size.1 {
+            let row = self.row_words(y);
+            let shifted_row = shifted.row_words_mut(y);
+
+            for (index, word) in shifted_row.iter_mut().enumerate().skip(word_shift) {
+                let source = index - word_shift;
+                *word = row[source] << bit_shift;
+
+                if bit_shift > 0 && source > 0 {
+                    *word |= row[source - 1] >> (WORD_BITS - bit_shift);
+                }
+            }
+        }
+
+        shifted.clear_padding();
+
+        shifted
+    }
+
+    // Moves every cell `n` columns to the left, cells pushed past the edge are dropped
+    pub fn shift_left(&self, n: usize) -> BitGrid {
+        let mut shifted = BitGrid::new(self.size);
+        let (word_shift, bit_shift) = (n / WORD_BITS, n % WORD_BITS);
+
+        for y in 0..self.size.1 {
+            let row = self.row_words(y);
+            let shifted_row = shifted.row_words_mut(y);
+
+            for (index, word) in shifted_row.iter_mut().enumerate() {
+                let source = index + word_shift;
+
+                if source >= row.len() {
+                    break;
+                }
+
+                *word = row[source] >> bit_shift;
+
+                if bit_shift > 0 && source + 1 < row.len() {
+                    *word |= row[source + 1] << (WORD_BITS - bit_shift);
+                }
+            }
+        }
+
+        shifted
+    }
+
+    // Moves every cell `n` rows up, cells pushed past the edge are dropped
+    pub fn shift_up(&self, n: usize) -> BitGrid {
+        let mut shifted = BitGrid::new(self.size);
+        let offset = n.min(self.size.1) * self.words_per_row;
+
+        shifted.words[..self.words.len() - offset].copy_from_slice(&self.words[offset..]);
+
+        shifted
+    }
+
+    // Moves every cell `n` rows down, cells pushed past the edge are dropped
+    pub fn shift_down(&self, n: usize) -> BitGrid {
+        let mut shifted = BitGrid::new(self.size);
+        let offset = n.min(self.size.1) * self.words_per_row;
+
+        shifted.words[offset..].copy_from_slice(&self.words[..self.words.len() - offset]);
+
+        shifted
+    }
+
+    fn position(&self, coord: Coord) -> (usize, usize) {
+        assert!(coord.0 < self.size.0);
+        assert!(coord.1 < self.size.1);
+
+        (
+            coord.1 * self.words_per_row + coord.0 / WORD_BITS,
+            coord.0 % WORD_BITS,
+        )
+    }
+
+    fn row_words(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn row_words_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn clear_padding(&mut self) {
+        let used_bits = self.size.0 % WORD_BITS;
+
+        if 0 == used_bits {
+            return;
+        }
+
+        let mask = (1u64 << used_bits) - 1;
+
+        for y in 0..self.size.1 {
+            let last = (y + 1) * self.words_per_row - 1;
+            self.words[last] &= mask;
+        }
+    }
+
+    fn combine<F>(&mut self, other: &BitGrid, op: F)
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        assert_eq!(self.size, other.size);
+
+        for (word, &other_word) in self.words.iter_mut().zip(other.words.iter()) {
+            *word = op(*word, other_word);
+        }
+    }
+}
+
+struct BitIter(u64);
+
+impl Iterator for BitIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if 0 == self.0 {
+            return None;
+        }
+
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+
+        Some(bit)
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.size);
+
+        for (coord, &value) in grid.iter() {
+            if value {
+                bits.set(coord, true);
+            }
+        }
+
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Self {
+        Grid::from_fn(bits.size, |coord| bits.get(coord))
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a & b);
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a | b);
+    }
+}
+
+impl BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a ^ b);
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result &= other;
+        result
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result |= other;
+        result
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result ^= other;
+        result
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut result = self.clone();
+        result.words.iter_mut().for_each(|word| *word = !*word);
+        result.clear_padding();
+        result
+    }
+}
+
+impl Debug for BitGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+
+        for y in 0..self.size.1 {
+            for x in 0..self.size.0 {
+                write!(f, "{}", if self.get((x, y)) { '#' } else { '.' })?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid(lines: &[&str]) -> BitGrid {
+        let grid = Grid::new(
+            (lines[0].len(), lines.len()),
+            lines
+                .iter()
+                .flat_map(|line| line.chars().map(|char| char == '#'))
+                .collect(),
+        );
+
+        BitGrid::from(&grid)
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = BitGrid::new((70, 2));
+
+        grid.set((0, 0), true);
+        grid.set((65, 1), true);
+        grid.toggle((3, 1));
+
+        assert!(grid.get((0, 0)));
+        assert!(grid.get((65, 1)));
+        assert!(grid.get((3, 1)));
+        assert!(!grid.get((64, 1)));
+        assert_eq!(grid.count_ones(), 3);
+        assert_eq!(
+            grid.iter_ones().collect::<Vec<Coord>>(),
+            [(0, 0), (3, 1), (65, 1)].to_vec()
+        );
+
+        grid.set((0, 0), false);
+        assert_eq!(grid.count_ones(), 2);
+    }
+
+    #[test]
+    fn test_bitwise_operations() {
+        let a = create_grid(&["##..", "#.#."]);
+        let b = create_grid(&["#.#.", "..##"]);
+
+        assert_eq!(&a & &b, create_grid(&["#...", "..#."]));
+        assert_eq!(&a | &b, create_grid(&["###.", "#.##"]));
+        assert_eq!(&a ^ &b, create_grid(&[".##.", "#..#"]));
+        assert_eq!(!&a, create_grid(&["..##", ".#.#"]));
+    }
+
+    #[test]
+    fn test_shifts() {
+        let grid = create_grid(&["#..#", ".##.", "...#"]);
+
+        assert_eq!(grid.shift_right(1), create_grid(&[".#..", "..##", "...."]));
+        assert_eq!(grid.shift_left(2), create_grid(&[".#..", "#...", ".#.."]));
+        assert_eq!(grid.shift_up(1), create_grid(&[".##.", "...#", "...."]));
+        assert_eq!(grid.shift_down(2), create_grid(&["....", "....", "#..#"]));
+        assert_eq!(grid.shift_down(5).count_ones(), 0);
+    }
+
+    #[test]
+    fn test_shifts_across_words() {
+        let mut grid = BitGrid::new((130, 1));
+        grid.set((62, 0), true);
+        grid.set((127, 0), true);
+
+        assert_eq!(
+            grid.shift_right(3).iter_ones().collect::<Vec<Coord>>(),
+            [(65, 0)].to_vec()
+        );
+        assert_eq!(
+            grid.shift_left(70).iter_ones().collect::<Vec<Coord>>(),
+            [(57, 0)].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        let grid = Grid::new((3, 2), [true, false, true, false, true, false].to_vec());
+
+        assert_eq!(Grid::from(&BitGrid::from(&grid)), grid);
+    }
+}
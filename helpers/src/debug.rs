@@ -1,6 +1,218 @@
+use std::fmt::Debug;
+
+use crate::{Coord, Grid};
+
+// Differing coordinates listed in the summary before the rest are only counted
+const MAX_LISTED_DIFFERENCES: usize = 10;
+
+const LEFT_HEADER: &str = "left";
+
 #[macro_export]
 macro_rules! debug {
     ($($expression:expr),*) => {
         $(println!("{} = {:?}", stringify!($expression), $expression);)*
     };
 }
+
+// Like `assert_eq!` for grids, but the panic message shows both grids side by side with the
+// differing cells highlighted
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let Some(diff) = $crate::grid_diff(&$left, &$right) {
+            panic!("assertion failed: `(left == right)`\n{}", diff);
+        }
+    };
+}
+
+// Report used by `assert_grid_eq!`, `None` when both grids are equal
+pub fn grid_diff<T: PartialEq + Debug>(left: &Grid<T>, right: &Grid<T>) -> Option<String> {
+    if left == right {
+        return None;
+    }
+
+    let size = (left.size.0.max(right.size.0), left.size.1.max(right.size.1));
+    let differing: Vec<Coord> = (0..size.1)
+        .flat_map(|y| (0..size.0).map(move |x| (x, y)))
+        .filter(|&coord| match (left.try_get(coord), right.try_get(coord)) {
+            (None, None) => false,
+            (Some(a), Some(b)) => a != b,
+            _ => true,
+        })
+        .collect();
+
+    let mut output = String::new();
+
+    if left.size != right.size {
+        output.push_str(&format!(
+            "sizes differ: left is {}x{}, right is {}x{}\n",
+            left.size.0, left.size.1, right.size.0, right.size.1
+        ));
+    }
+
+    let listed: Vec<String> = differing
+        .iter()
+        .take(MAX_LISTED_DIFFERENCES)
+        .map(|coord| format!("{:?}", coord))
+        .collect();
+    output.push_str(&format!(
+        "{} differing {}: {}",
+        differing.len(),
+        if differing.len() == 1 {
+            "cell"
+        } else {
+            "cells"
+        },
+        listed.join(", ")
+    ));
+    if differing.len() > MAX_LISTED_DIFFERENCES {
+        output.push_str(&format!(
+            " and {} more",
+            differing.len() - MAX_LISTED_DIFFERENCES
+        ));
+    }
+    output.push('\n');
+
+    let width = left
+        .iter()
+        .chain(right.iter())
+        .map(|(_, cell)| format!("{:?}", cell).chars().count())
+        .max()
+        .unwrap_or(0);
+    let left_rows = diff_rows(left, &differing, width);
+    let right_rows = diff_rows(right, &differing, width);
+    let left_width = (left.size.0 * (width + 1)).max(LEFT_HEADER.chars().count());
+
+    output.push_str(&format!("{:<1$} | right\n", LEFT_HEADER, left_width));
+
+    for y in 0..size.1 {
+        let (left_row, right_row) = (left_rows.get(y), right_rows.get(y));
+        let padding = left_width - left_row.map_or(0, |(_, len)| *len);
+
+        output.push_str(&format!(
+            "{}{} | {}\n",
+            left_row.map_or("", |(row, _)| row),
+            " ".repeat(padding),
+            right_row.map_or("", |(row, _)| row)
+        ));
+    }
+
+    Some(output)
+}
+
+// Every row rendered with its printed length, since the highlighting escapes take no room
+fn diff_rows<T: Debug>(grid: &Grid<T>, differing: &[Coord], width: usize) -> Vec<(String, usize)> {
+    grid.rows()
+        .enumerate()
+        .map(|(y, row)| {
+            let cells: Vec<(String, usize)> = row
+                .iter()
+                .enumerate()
+                .map(|(x, cell)| {
+                    let cell = format!("{:>1$?}", cell, width);
+                    let len = cell.chars().count();
+
+                    if differing.contains(&(x, y)) {
+                        (
+                            format!("\x1b[1;38;5;{}m{}\x1b[0m", crate::HIGHLIGHT_COLOR, cell),
+                            len,
+                        )
+                    } else {
+                        (cell, len)
+                    }
+                })
+                .collect();
+
+            (
+                cells
+                    .iter()
+                    .map(|(cell, _)| cell.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+                    + " ",
+                cells.iter().map(|(_, len)| len + 1).sum(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_diff() {
+        let left = Grid::new((2, 2), [1, 2, 3, 4].to_vec());
+        let right = Grid::new((2, 2), [1, 2, 3, 5].to_vec());
+
+        assert_eq!(grid_diff(&left, &left.clone()), None);
+        assert_eq!(
+            grid_diff(&left, &right),
+            Some(
+                [
+                    "1 differing cell: (1, 1)\n",
+                    "left | right\n",
+                    "1 2  | 1 2 \n",
+                    "3 \x1b[1;38;5;196m4\x1b[0m  | 3 \x1b[1;38;5;196m5\x1b[0m \n",
+                ]
+                .concat()
+            )
+        );
+    }
+
+    #[test]
+    fn test_grid_diff_narrow() {
+        let left = Grid::new((1, 1), [1].to_vec());
+        let right = Grid::new((1, 1), [2].to_vec());
+
+        assert_eq!(
+            grid_diff(&left, &right),
+            Some(
+                [
+                    "1 differing cell: (0, 0)\n",
+                    "left | right\n",
+                    "\x1b[1;38;5;196m1\x1b[0m    | \x1b[1;38;5;196m2\x1b[0m \n",
+                ]
+                .concat()
+            )
+        );
+    }
+
+    #[test]
+    fn test_grid_diff_non_ascii() {
+        let left = Grid::new((2, 1), ['é', 'a'].to_vec());
+        let right = Grid::new((2, 1), ['e', 'a'].to_vec());
+
+        assert_eq!(
+            grid_diff(&left, &right),
+            Some(
+                [
+                    "1 differing cell: (0, 0)\n",
+                    "left     | right\n",
+                    "\x1b[1;38;5;196m'é'\x1b[0m 'a'  | \x1b[1;38;5;196m'e'\x1b[0m 'a' \n",
+                ]
+                .concat()
+            )
+        );
+    }
+
+    #[test]
+    fn test_grid_diff_sizes() {
+        let left = Grid::new((2, 1), [1, 2].to_vec());
+        let right = Grid::new((1, 2), [1, 2].to_vec());
+
+        assert_ne!(left, right);
+        assert!(grid_diff(&left, &right).unwrap().starts_with(
+            "sizes differ: left is 2x1, right is 1x2\n2 differing cells: (1, 0), (0, 1)\n"
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "1 differing cell: (0, 0)")]
+    fn test_assert_grid_eq() {
+        assert_grid_eq!(
+            Grid::new((1, 1), ['a'].to_vec()),
+            Grid::new((1, 1), ['b'].to_vec())
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use fixedbitset::FixedBitSet;
+use petgraph::visit::{
+    Data, EdgeRef, GraphBase, IntoEdgeReferences, IntoEdges, IntoNeighbors, NodeIndexable,
+    VisitMap, Visitable,
+};
+
+use crate::{Coord, Edges, Grid, NeighborCoordIter, Neighborhood};
+
+type GridEdges<'b, W> = Box<dyn Iterator<Item = GridEdge<W>> + 'b>;
+
+// Grid seen as a directed graph without copying it: every cell is a node with an edge to each
+// of its neighbors, weighted by `weight` from the source to the target cell
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    neighborhood: Neighborhood<'a>,
+    weight: F,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridEdge<W> {
+    source: Coord,
+    target: Coord,
+    weight: W,
+}
+
+impl<T> Grid<T> {
+    pub fn as_graph<'a, W, F>(
+        &'a self,
+        neighborhood: Neighborhood<'a>,
+        weight: F,
+    ) -> GridGraph<'a, T, F>
+    where
+        F: Fn((Coord, &T), (Coord, &T)) -> W,
+    {
+        GridGraph {
+            grid: self,
+            neighborhood,
+            weight,
+        }
+    }
+}
+
+impl<'a, T, W, F> GridGraph<'a, T, F>
+where
+    W: Copy,
+    F: Fn((Coord, &T), (Coord, &T)) -> W,
+{
+    fn edge(&self, source: Coord, target: Coord) -> GridEdge<W> {
+        GridEdge {
+            source,
+            target,
+            weight: (self.weight)(
+                (source, self.grid.get(source)),
+                (target, self.grid.get(target)),
+            ),
+        }
+    }
+}
+
+impl<W: Copy> EdgeRef for GridEdge<W> {
+    type NodeId = Coord;
+    type EdgeId = (Coord, Coord);
+    type Weight = W;
+
+    fn source(&self) -> Coord {
+        self.source
+    }
+
+    fn target(&self) -> Coord {
+        self.target
+    }
+
+    fn weight(&self) -> &W {
+        &self.weight
+    }
+
+    fn id(&self) -> (Coord, Coord) {
+        (self.source, self.target)
+    }
+}
+
+impl<'a, T, F> GraphBase for GridGraph<'a, T, F> {
+    type NodeId = Coord;
+    type EdgeId = (Coord, Coord);
+}
+
+impl<'a, T, W, F> Data for GridGraph<'a, T, F>
+where
+    F: Fn((Coord, &T), (Coord, &T)) -> W,
+{
+    type NodeWeight = T;
+    type EdgeWeight = W;
+}
+
+impl<'a, T, F> IntoNeighbors for &GridGraph<'a, T, F> {
+    type Neighbors = NeighborCoordIter<'a>;
+
+    fn neighbors(self, coord: Coord) -> Self::Neighbors {
+        NeighborCoordIter::new(self.grid.size, coord, self.neighborhood, Edges::Bounded)
+    }
+}
+
+impl<'a, 'b, T, W, F> IntoEdgeReferences for &'b GridGraph<'a, T, F>
+where
+    W: Copy + 'b,
+    F: Fn((Coord, &T), (Coord, &T)) -> W,
+{
+    type EdgeRef = GridEdge<W>;
+    type EdgeReferences = GridEdges<'b, W>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        Box::new((0..self.grid.len()).flat_map(move |index| self.edges(self.grid.coord(index))))
+    }
+}
+
+impl<'a, 'b, T, W, F> IntoEdges for &'b GridGraph<'a, T, F>
+where
+    W: Copy + 'b,
+    F: Fn((Coord, &T), (Coord, &T)) -> W,
+{
+    type Edges = GridEdges<'b, W>;
+
+    fn edges(self, coord: Coord) -> Self::Edges {
+        Box::new(
+            self.neighbors(coord)
+                .map(move |neighbor| self.edge(coord, neighbor)),
+        )
+    }
+}
+
+impl<'a, T, F> NodeIndexable for GridGraph<'a, T, F> {
+    fn node_bound(&self) -> usize {
+        self.grid.len()
+    }
+
+    fn to_index(&self, coord: Coord) -> usize {
+        self.grid.index(coord)
+    }
+
+    fn from_index(&self, index: usize) -> Coord {
+        self.grid.coord(index)
+    }
+}
+
+impl<'a, T, F> Visitable for GridGraph<'a, T, F> {
+    type Map = CoordVisitMap;
+
+    fn visit_map(&self) -> Self::Map {
+        CoordVisitMap {
+            width: self.grid.size.0,
+            visited: FixedBitSet::with_capacity(self.grid.len()),
+        }
+    }
+
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.visited.clear();
+    }
+}
+
+// Visited cells as bits in row-major order, the same indices as `NodeIndexable::to_index`
+#[derive(Debug, Clone)]
+pub struct CoordVisitMap {
+    width: usize,
+    visited: FixedBitSet,
+}
+
+impl VisitMap<Coord> for CoordVisitMap {
+    fn visit(&mut self, coord: Coord) -> bool {
+        !self.visited.put(coord.0 + coord.1 * self.width)
+    }
+
+    fn is_visited(&self, coord: &Coord) -> bool {
+        self.visited.contains(coord.0 + coord.1 * self.width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::algo::{astar, dijkstra};
+    use petgraph::visit::{Bfs, EdgeRef};
+
+    use super::*;
+
+    fn create_grid() -> Grid<usize> {
+        Grid::new(
+            (3, 3),
+            [
+                1, 1, 6, //
+                1, 3, 8, //
+                2, 1, 3, //
+            ]
+            .to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = create_grid();
+        let graph = grid.as_graph(Neighborhood::VonNeumann, |_, (_, &risk)| risk);
+
+        assert_eq!(
+            astar(
+                &graph,
+                (0, 0),
+                |coord| coord == (2, 2),
+                |edge| *edge.weight(),
+                |_| 0
+            ),
+            Some((7, [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)].to_vec()))
+        );
+
+        let costs = dijkstra(&graph, (0, 0), None, |edge| *edge.weight());
+        assert_eq!(costs.len(), 9);
+        assert_eq!(costs[&(2, 0)], 7);
+    }
+
+    #[test]
+    fn test_traversal() {
+        let grid = create_grid();
+        let graph = grid.as_graph(Neighborhood::Moore, |_, _| ());
+        let mut bfs = Bfs::new(&graph, (1, 1));
+        let mut visited = 0;
+
+        while bfs.next(&graph).is_some() {
+            visited += 1;
+        }
+
+        assert_eq!(visited, 9);
+        assert_eq!(graph.neighbors((0, 0)).count(), 3);
+        assert_eq!(graph.edge_references().count(), 40);
+        assert_eq!(graph.to_index((1, 2)), 7);
+        assert_eq!(graph.from_index(7), (1, 2));
+
+        let mut map = graph.visit_map();
+
+        assert!(map.visit((2, 1)));
+        assert!(!map.visit((2, 1)));
+        assert!(map.is_visited(&(2, 1)));
+        assert!(!map.is_visited(&(1, 2)));
+
+        graph.reset_map(&mut map);
+
+        assert!(!map.is_visited(&(2, 1)));
+    }
+}
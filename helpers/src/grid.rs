@@ -1,12 +1,16 @@
 use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+use crate::{Edges, GridBuilder, NeighborCoordIter, Neighborhood};
 
 pub type Coord = (usize, usize);
 
 #[derive(Clone)]
 pub struct Grid<T> {
     pub size: (usize, usize),
-    cells: Vec<T>,
+    pub(crate) cells: Vec<T>,
 }
 
 impl<T> Grid<T> {
@@ -16,15 +20,71 @@ impl<T> Grid<T> {
         Grid { size, cells }
     }
 
+    pub fn from_fn<F>(size: (usize, usize), mut f: F) -> Self
+    where
+        F: FnMut(Coord) -> T,
+    {
+        let cells = (0..size.0 * size.1)
+            .map(|index| f(Self::_coord(size, index)))
+            .collect();
+
+        Grid { size, cells }
+    }
+
     pub fn len(&self) -> usize {
         self.size.0 * self.size.1
     }
 
+    pub fn is_empty(&self) -> bool {
+        0 == self.len()
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.0 < self.size.0 && coord.1 < self.size.1
+    }
+
     pub fn get(&self, coord: Coord) -> &T {
         &self.cells[self.index(coord)]
     }
 
-    pub fn get_mut<'a>(&'a mut self, coord: Coord) -> &'a mut T {
+    pub fn try_get(&self, coord: Coord) -> Option<&T> {
+        if self.contains(coord) {
+            Some(self.get(coord))
+        } else {
+            None
+        }
+    }
+
+    pub fn try_get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            Some(self.get_mut(coord))
+        } else {
+            None
+        }
+    }
+
+    // Coordinate `dx` columns and `dy` rows away from `coord`, if it's inside the grid
+    pub fn offset(&self, coord: Coord, dx: isize, dy: isize) -> Option<Coord> {
+        let x = coord.0 as isize + dx;
+        let y = coord.1 as isize + dy;
+
+        if x < 0 || y < 0 || !self.contains((x as usize, y as usize)) {
+            return None;
+        }
+
+        Some((x as usize, y as usize))
+    }
+
+    pub fn get_offset(&self, coord: Coord, dx: isize, dy: isize) -> Option<&T> {
+        self.offset(coord, dx, dy).map(|coord| self.get(coord))
+    }
+
+    pub fn get_offset_mut(&mut self, coord: Coord, dx: isize, dy: isize) -> Option<&mut T> {
+        self.offset(coord, dx, dy)
+            .map(move |coord| self.get_mut(coord))
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> &mut T {
         let index = self.index(coord);
         self.cells.get_mut(index).unwrap()
     }
@@ -34,7 +94,11 @@ impl<T> Grid<T> {
         self.cells[index] = value;
     }
 
-    pub fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = (Coord, &T)> + 'a> {
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    pub fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = (Coord, &'a T)> + 'a> {
         Box::new(
             self.cells
                 .iter()
@@ -54,25 +118,251 @@ impl<T> Grid<T> {
         )
     }
 
-    pub fn neighbors_iter(&self, coord: Coord, with_diagonals: bool) -> NeighborIter<T> {
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.size.1);
+
+        &self.cells[y * self.size.0..(y + 1) * self.size.0]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.size.1);
+
+        &mut self.cells[y * self.size.0..(y + 1) * self.size.0]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.size.0);
+
+        self.cells.iter().skip(x).step_by(self.size.0)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.size.1).map(move |y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.size.0).map(move |x| self.column(x))
+    }
+
+    // Top-left to bottom-right lines, starting from the bottom-left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let (width, height) = self.line_bounds();
+        let starts = (1..height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((0..width).map(|x| (x, 0)));
+
+        starts.map(move |start| {
+            (0..)
+                .map(move |step| (start.0 + step, start.1 + step))
+                .take_while(move |&(x, y)| x < width && y < height)
+                .map(move |coord| self.get(coord))
+        })
+    }
+
+    // Top-right to bottom-left lines, starting from the top-left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let (width, height) = self.line_bounds();
+        let starts = (0..width)
+            .map(|x| (x, 0))
+            .chain((1..height).map(move |y| (width - 1, y)));
+
+        starts.map(move |start| {
+            (0..)
+                .take_while(move |&step| step <= start.0 && start.1 + step < height)
+                .map(move |step| self.get((start.0 - step, start.1 + step)))
+        })
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn((self.size.1, self.size.0), |(x, y)| {
+            self.get((y, x)).clone()
+        })
+    }
+
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.size.1;
+
+        Grid::from_fn((self.size.1, self.size.0), |(x, y)| {
+            self.get((y, height - 1 - x)).clone()
+        })
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.size.0;
+
+        Grid::from_fn((self.size.1, self.size.0), |(x, y)| {
+            self.get((width - 1 - y, x)).clone()
+        })
+    }
+
+    // Mirrors left to right
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.size.0;
+
+        Grid::from_fn(self.size, |(x, y)| self.get((width - 1 - x, y)).clone())
+    }
+
+    // Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.size.1;
+
+        Grid::from_fn(self.size, |(x, y)| self.get((x, height - 1 - y)).clone())
+    }
+
+    pub fn subgrid(&self, origin: Coord, size: (usize, usize)) -> Grid<T>
+    where
+        T: Clone,
+    {
+        assert!(origin.0 + size.0 <= self.size.0);
+        assert!(origin.1 + size.1 <= self.size.1);
+
+        Grid::from_fn(size, |(x, y)| {
+            self.get((origin.0 + x, origin.1 + y)).clone()
+        })
+    }
+
+    // Cells inside the inclusive `(min, max)` corners together with `min`, the position of the new
+    // top left corner in this grid
+    pub fn crop(&self, rect: (Coord, Coord)) -> (Grid<T>, Coord)
+    where
+        T: Clone,
+    {
+        let (min, max) = rect;
+        assert!(min.0 <= max.0 && min.1 <= max.1);
+
+        (
+            self.subgrid(min, (max.0 - min.0 + 1, max.1 - min.1 + 1)),
+            min,
+        )
+    }
+
+    // Surrounds the grid with `n` cells of `fill` on every side, together with `(n, n)`, the
+    // position of this grid's top left corner in the new one
+    pub fn pad(&self, n: usize, fill: T) -> (Grid<T>, Coord)
+    where
+        T: Clone,
+    {
+        let grid = Grid::from_fn((self.size.0 + 2 * n, self.size.1 + 2 * n), |(x, y)| {
+            if x < n || y < n {
+                return fill.clone();
+            }
+
+            self.try_get((x - n, y - n))
+                .cloned()
+                .unwrap_or_else(|| fill.clone())
+        });
+
+        (grid, (n, n))
+    }
+
+    // Grows or shrinks the grid keeping the top left corner in place, new cells are `fill`. Every
+    // kept cell stays at the same coordinate, so unlike `crop` and `pad` there is no offset.
+    pub fn resize(&self, size: (usize, usize), fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(size, |coord| {
+            self.try_get(coord).cloned().unwrap_or_else(|| fill.clone())
+        })
+    }
+
+    // Inclusive `(min, max)` corners of the cells matching `predicate`
+    pub fn bounding_box<P>(&self, predicate: P) -> Option<(Coord, Coord)>
+    where
+        P: Fn(Coord, &T) -> bool,
+    {
+        self.iter()
+            .filter(|&(coord, cell)| predicate(coord, cell))
+            .fold(None, |bounds, ((x, y), _)| match bounds {
+                None => Some(((x, y), (x, y))),
+                Some((min, max)) => {
+                    Some(((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y))))
+                }
+            })
+    }
+
+    // Smallest grid holding every cell matching `predicate` together with the position of its
+    // top left corner in this grid, empty when nothing matches
+    pub fn trimmed<P>(&self, predicate: P) -> (Grid<T>, Coord)
+    where
+        T: Clone,
+        P: Fn(Coord, &T) -> bool,
+    {
+        match self.bounding_box(predicate) {
+            Some(rect) => self.crop(rect),
+            None => (Grid::new((0, 0), Vec::new()), (0, 0)),
+        }
+    }
+
+    pub fn neighbors_iter(&self, coord: Coord, with_diagonals: bool) -> NeighborIter<'_, T> {
+        self.neighbors(
+            coord,
+            Neighborhood::from_diagonals(with_diagonals),
+            Edges::Bounded,
+        )
+    }
+
+    pub fn neighbors<'a>(
+        &'a self,
+        coord: Coord,
+        neighborhood: Neighborhood<'a>,
+        edges: Edges,
+    ) -> NeighborIter<'a, T> {
         assert!(coord.0 < self.size.0);
         assert!(coord.1 < self.size.1);
 
-        NeighborIter::new(&self, coord, with_diagonals)
+        NeighborIter::with_neighborhood(self, coord, neighborhood, edges)
+    }
+
+    pub fn neighbor_coords<'a>(
+        &self,
+        coord: Coord,
+        neighborhood: Neighborhood<'a>,
+        edges: Edges,
+    ) -> NeighborCoordIter<'a> {
+        assert!(coord.0 < self.size.0);
+        assert!(coord.1 < self.size.1);
+
+        NeighborCoordIter::new(self.size, coord, neighborhood, edges)
+    }
+
+    // A grid with no width or no height has no lines to walk, whatever its other dimension
+    fn line_bounds(&self) -> (usize, usize) {
+        if self.is_empty() {
+            (0, 0)
+        } else {
+            self.size
+        }
     }
 
-    fn index(&self, coord: Coord) -> usize {
+    pub(crate) fn index(&self, coord: Coord) -> usize {
         assert!(coord.0 < self.size.0);
         assert!(coord.1 < self.size.1);
 
         coord.0 + coord.1 * self.size.0
     }
 
-    fn coord(&self, index: usize) -> Coord {
+    pub(crate) fn coord(&self, index: usize) -> Coord {
         Self::_coord(self.size, index)
     }
 
-    fn _coord(size: (usize, usize), index: usize) -> Coord {
+    pub(crate) fn _coord(size: (usize, usize), index: usize) -> Coord {
         (index % size.0, index / size.0)
     }
 }
@@ -95,124 +385,66 @@ impl<T: Debug> Debug for Grid<T> {
 
 impl<T: PartialEq> PartialEq for Grid<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.cells == other.cells
+        self.size == other.size && self.cells == other.cells
     }
 }
 
-impl<T> FromIterator<(Coord, T)> for Grid<T> {
-    fn from_iter<K: IntoIterator<Item = (Coord, T)>>(iter: K) -> Self {
-        let mut cells = Vec::new();
-        let mut size = (0, 0);
-
-        for (coord, value) in iter.into_iter() {
-            cells.push(value);
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
 
-            if coord.0 > size.0 {
-                size.0 = coord.0;
-            }
-
-            if coord.1 > size.1 {
-                size.1 = coord.1;
-            }
-        }
-
-        Grid::new((size.0 + 1, size.1 + 1), cells)
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
     }
 }
 
-struct NeighborCoordIter<'a, T> {
-    grid: &'a Grid<T>,
-    coord: Coord,
-    visited: Vec<usize>,
-    with_diagonals: bool,
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+    }
 }
 
-impl<'a, T> NeighborCoordIter<'a, T> {
-    pub fn new(grid: &'a Grid<T>, coord: Coord, with_diagonals: bool) -> Self {
-        NeighborCoordIter {
-            grid,
-            coord,
-            visited: vec![],
-            with_diagonals,
-        }
+impl<T: Eq> Eq for Grid<T> {}
+
+impl<T: Hash> Hash for Grid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cells.hash(state);
     }
 }
 
-impl<'a, T> Iterator for NeighborCoordIter<'a, T> {
-    type Item = Coord;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        // Left
-        if !self.visited.contains(&0) && self.coord.0 != 0 {
-            self.visited.push(0);
-            Some((self.coord.0 - 1, self.coord.1))
-        } else
-        // Left-top
-        if self.with_diagonals
-            && !self.visited.contains(&1)
-            && self.coord.0 != 0
-            && self.coord.1 != 0
-        {
-            self.visited.push(1);
-            Some((self.coord.0 - 1, self.coord.1 - 1))
-        } else
-        // Top
-        if !self.visited.contains(&2) && self.coord.1 != 0 {
-            self.visited.push(2);
-            Some((self.coord.0, self.coord.1 - 1))
-        } else
-        // Top-right
-        if self.with_diagonals
-            && !self.visited.contains(&3)
-            && self.coord.0 + 1 != self.grid.size.0
-            && self.coord.1 != 0
-        {
-            self.visited.push(3);
-            Some((self.coord.0 + 1, self.coord.1 - 1))
-        } else
-        // Right
-        if !self.visited.contains(&4) && self.coord.0 + 1 != self.grid.size.0 {
-            self.visited.push(4);
-            Some((self.coord.0 + 1, self.coord.1))
-        } else
-        // Bottom-right
-        if self.with_diagonals
-            && !self.visited.contains(&5)
-            && self.coord.0 + 1 != self.grid.size.0
-            && self.coord.1 + 1 != self.grid.size.1
-        {
-            self.visited.push(5);
-            Some((self.coord.0 + 1, self.coord.1 + 1))
-        } else
-        // Bottom
-        if !self.visited.contains(&6) && self.coord.1 + 1 != self.grid.size.1 {
-            self.visited.push(6);
-            Some((self.coord.0, self.coord.1 + 1))
-        } else
-        // Bottom-left
-        if self.with_diagonals
-            && !self.visited.contains(&7)
-            && self.coord.0 != 0
-            && self.coord.1 + 1 != self.grid.size.1
-        {
-            self.visited.push(7);
-            Some((self.coord.0 - 1, self.coord.1 + 1))
-        } else {
-            None
+// Panics on duplicated or missing cells, use `GridBuilder` to handle them
+impl<T> FromIterator<(Coord, T)> for Grid<T> {
+    fn from_iter<K: IntoIterator<Item = (Coord, T)>>(iter: K) -> Self {
+        match iter.into_iter().collect::<GridBuilder<T>>().build() {
+            Ok(grid) => grid,
+            Err(error) => panic!("Invalid grid cells: {}", error),
         }
     }
 }
 
 pub struct NeighborIter<'a, T> {
     grid: &'a Grid<T>,
-    coord_iter: NeighborCoordIter<'a, T>,
+    coord_iter: NeighborCoordIter<'a>,
 }
 
 impl<'a, T> NeighborIter<'a, T> {
     pub fn new(grid: &'a Grid<T>, coord: Coord, with_diagonals: bool) -> Self {
+        Self::with_neighborhood(
+            grid,
+            coord,
+            Neighborhood::from_diagonals(with_diagonals),
+            Edges::Bounded,
+        )
+    }
+
+    pub fn with_neighborhood(
+        grid: &'a Grid<T>,
+        coord: Coord,
+        neighborhood: Neighborhood<'a>,
+        edges: Edges,
+    ) -> Self {
         NeighborIter {
             grid,
-            coord_iter: NeighborCoordIter::new(grid, coord, with_diagonals),
+            coord_iter: NeighborCoordIter::new(grid.size, coord, neighborhood, edges),
         }
     }
 }
@@ -280,6 +512,49 @@ mod tests {
         assert_eq!(grid.get((2, 3)), &'x');
     }
 
+    #[test]
+    fn test_checked_access() {
+        let mut grid = create_grid();
+
+        assert!(grid.contains((3, 4)));
+        assert!(!grid.contains((4, 0)));
+        assert_eq!(grid.try_get((2, 3)), Some(&'o'));
+        assert_eq!(grid.try_get((0, 5)), None);
+
+        *grid.try_get_mut((1, 1)).unwrap() = 'x';
+        assert_eq!(grid.get((1, 1)), &'x');
+        assert_eq!(grid.try_get_mut((9, 9)), None);
+    }
+
+    #[test]
+    fn test_offset_access() {
+        let mut grid = create_grid();
+
+        assert_eq!(grid.offset((1, 1), -1, 2), Some((0, 3)));
+        assert_eq!(grid.get_offset((1, 1), 2, -1), Some(&'d'));
+        assert_eq!(grid.get_offset((1, 1), -2, 0), None);
+        assert_eq!(grid.get_offset((3, 4), 1, 0), None);
+
+        *grid.get_offset_mut((0, 0), 1, 0).unwrap() = 'x';
+        assert_eq!(grid.get((1, 0)), &'x');
+    }
+
+    #[test]
+    fn test_index() {
+        let mut grid = create_grid();
+        assert_eq!(grid[(2, 3)], 'o');
+
+        grid[(2, 3)] = 'x';
+        assert_eq!(grid[(2, 3)], 'x');
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = create_grid();
+        let _ = grid[(4, 0)];
+    }
+
     #[test]
     fn test_iter_cells() {
         let grid = create_grid();
@@ -323,6 +598,22 @@ mod tests {
         assert_eq!(grid.get((0, 0)), &'x');
     }
 
+    #[test]
+    fn test_collect_unordered_cells() {
+        let grid: Grid<char> = [((1, 0), 'b'), ((0, 1), 'c'), ((1, 1), 'd'), ((0, 0), 'a')]
+            .iter()
+            .cloned()
+            .collect();
+
+        assert_eq!(grid, Grid::new((2, 2), ['a', 'b', 'c', 'd'].to_vec()));
+    }
+
+    #[test]
+    #[should_panic(expected = "missing cell at (1, 0)")]
+    fn test_collect_cells_with_gaps() {
+        let _: Grid<char> = [((0, 0), 'a'), ((1, 1), 'd')].iter().cloned().collect();
+    }
+
     #[test]
     fn test_iter_cell_neighbors() {
         let grid = create_grid();
@@ -397,4 +688,184 @@ mod tests {
             [((2, 4), &'s'), ((2, 3), &'o'), ((3, 3), &'p')].to_vec()
         );
     }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = create_grid();
+
+        assert_eq!(grid.row(1), &['e', 'f', 'g', 'h']);
+        assert_eq!(
+            grid.column(2).collect::<Vec<&char>>(),
+            [&'c', &'g', &'k', &'o', &'s'].to_vec()
+        );
+        assert_eq!(grid.rows().count(), 5);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<String>>(),
+            ["aeimq", "bfjnr", "cgkos", "dhlpt"].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.diagonals()
+                .map(|diagonal| diagonal.collect::<String>())
+                .collect::<Vec<String>>(),
+            ["q", "mr", "ins", "ejot", "afkp", "bgl", "ch", "d"].to_vec()
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|diagonal| diagonal.collect::<String>())
+                .collect::<Vec<String>>(),
+            ["a", "be", "cfi", "dgjm", "hknq", "lor", "ps", "t"].to_vec()
+        );
+
+        let empty: Grid<char> = Grid::new((0, 3), Vec::new());
+
+        assert_eq!(empty.diagonals().count(), 0);
+        assert_eq!(empty.anti_diagonals().count(), 0);
+        assert_eq!(empty.transpose().diagonals().count(), 0);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = Grid::new((3, 2), ['a', 'b', 'c', 'd', 'e', 'f'].to_vec());
+
+        assert_eq!(
+            grid.transpose(),
+            Grid::new((2, 3), ['a', 'd', 'b', 'e', 'c', 'f'].to_vec())
+        );
+        assert_eq!(
+            grid.rotate_cw(),
+            Grid::new((2, 3), ['d', 'a', 'e', 'b', 'f', 'c'].to_vec())
+        );
+        assert_eq!(
+            grid.rotate_ccw(),
+            Grid::new((2, 3), ['c', 'f', 'b', 'e', 'a', 'd'].to_vec())
+        );
+        assert_eq!(
+            grid.flip_horizontal(),
+            Grid::new((3, 2), ['c', 'b', 'a', 'f', 'e', 'd'].to_vec())
+        );
+        assert_eq!(
+            grid.flip_vertical(),
+            Grid::new((3, 2), ['d', 'e', 'f', 'a', 'b', 'c'].to_vec())
+        );
+    }
+
+    #[test]
+    fn test_subgrid() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.subgrid((1, 2), (2, 3)),
+            Grid::new((2, 3), ['j', 'k', 'n', 'o', 'r', 's'].to_vec())
+        );
+    }
+
+    #[test]
+    fn test_crop() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.crop(((1, 2), (2, 4))),
+            (
+                Grid::new((2, 3), ['j', 'k', 'n', 'o', 'r', 's'].to_vec()),
+                (1, 2)
+            )
+        );
+    }
+
+    #[test]
+    fn test_pad_and_resize() {
+        let grid = Grid::new((2, 1), ['a', 'b'].to_vec());
+
+        assert_eq!(
+            grid.pad(1, '.'),
+            (
+                Grid::new(
+                    (4, 3),
+                    [
+                        '.', '.', '.', '.', //
+                        '.', 'a', 'b', '.', //
+                        '.', '.', '.', '.', //
+                    ]
+                    .to_vec()
+                ),
+                (1, 1)
+            )
+        );
+        assert_eq!(
+            grid.resize((3, 2), '.'),
+            Grid::new((3, 2), ['a', 'b', '.', '.', '.', '.'].to_vec())
+        );
+        assert_eq!(grid.resize((1, 1), '.'), Grid::new((1, 1), ['a'].to_vec()));
+    }
+
+    #[test]
+    fn test_bounding_box_and_trimmed() {
+        let grid = Grid::new(
+            (4, 3),
+            [
+                '.', '.', '.', '.', //
+                '.', '#', '.', '.', //
+                '.', '.', '#', '.', //
+            ]
+            .to_vec(),
+        );
+        let lit = |_, &cell: &char| cell == '#';
+
+        assert_eq!(grid.bounding_box(lit), Some(((1, 1), (2, 2))));
+        assert_eq!(
+            grid.trimmed(lit),
+            (Grid::new((2, 2), ['#', '.', '.', '#'].to_vec()), (1, 1))
+        );
+        assert_eq!(grid.bounding_box(|_, &cell| cell == 'x'), None);
+        assert_eq!(
+            grid.trimmed(|_, &cell| cell == 'x'),
+            (Grid::new((0, 0), Vec::new()), (0, 0))
+        );
+    }
+
+    #[test]
+    fn test_iter_cell_neighbors_toroidal() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.neighbors((0, 0), Neighborhood::VonNeumann, Edges::Toroidal)
+                .collect::<Vec<(Coord, &char)>>(),
+            [
+                ((3, 0), &'d'),
+                ((0, 4), &'q'),
+                ((1, 0), &'b'),
+                ((0, 1), &'e')
+            ]
+            .to_vec()
+        );
+    }
+
+    #[test]
+    fn test_neighbor_coords_with_radius() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.neighbor_coords((0, 4), Neighborhood::Radius(2), Edges::Bounded)
+                .collect::<Vec<Coord>>(),
+            [
+                (0, 2),
+                (1, 2),
+                (2, 2),
+                (0, 3),
+                (1, 3),
+                (2, 3),
+                (1, 4),
+                (2, 4)
+            ]
+            .to_vec()
+        );
+    }
 }
@@ -0,0 +1,178 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::FromIterator;
+
+use crate::{Coord, Grid};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridBuildError {
+    Duplicate(Coord),
+    // First missing coordinate in row-major order
+    Gap(Coord),
+    OutOfBounds(Coord),
+}
+
+impl Display for GridBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridBuildError::Duplicate(coord) => write!(f, "duplicate cell at {:?}", coord),
+            GridBuildError::Gap(coord) => write!(f, "missing cell at {:?}", coord),
+            GridBuildError::OutOfBounds(coord) => {
+                write!(f, "cell at {:?} is outside the grid", coord)
+            }
+        }
+    }
+}
+
+impl Error for GridBuildError {}
+
+type PlacedCells<T> = ((usize, usize), Vec<Option<T>>);
+
+// Collects cells in any order, the size is inferred from the largest coordinates unless set
+pub struct GridBuilder<T> {
+    size: Option<(usize, usize)>,
+    cells: Vec<(Coord, T)>,
+}
+
+impl<T> GridBuilder<T> {
+    pub fn new() -> Self {
+        GridBuilder {
+            size: None,
+            cells: Vec::new(),
+        }
+    }
+
+    pub fn with_size(mut self, size: (usize, usize)) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn insert(&mut self, coord: Coord, value: T) -> &mut Self {
+        self.cells.push((coord, value));
+        self
+    }
+
+    pub fn build(self) -> Result<Grid<T>, GridBuildError> {
+        let (size, cells) = self.place()?;
+
+        cells
+            .into_iter()
+            .enumerate()
+            .map(|(index, cell)| cell.ok_or(GridBuildError::Gap((index % size.0, index / size.0))))
+            .collect::<Result<Vec<T>, GridBuildError>>()
+            .map(|cells| Grid::new(size, cells))
+    }
+
+    pub fn build_with_fill(self, fill: T) -> Result<Grid<T>, GridBuildError>
+    where
+        T: Clone,
+    {
+        let (size, cells) = self.place()?;
+
+        Ok(Grid::new(
+            size,
+            cells
+                .into_iter()
+                .map(|cell| cell.unwrap_or_else(|| fill.clone()))
+                .collect(),
+        ))
+    }
+
+    fn place(self) -> Result<PlacedCells<T>, GridBuildError> {
+        let size = self.size.unwrap_or_else(|| {
+            self.cells.iter().fold((0, 0), |size, ((x, y), _)| {
+                (size.0.max(x + 1), size.1.max(y + 1))
+            })
+        });
+        let mut cells: Vec<Option<T>> = (0..size.0 * size.1).map(|_| None).collect();
+
+        for (coord, value) in self.cells {
+            if coord.0 >= size.0 || coord.1 >= size.1 {
+                return Err(GridBuildError::OutOfBounds(coord));
+            }
+
+            let cell = &mut cells[coord.0 + coord.1 * size.0];
+
+            if cell.is_some() {
+                return Err(GridBuildError::Duplicate(coord));
+            }
+
+            *cell = Some(value);
+        }
+
+        Ok((size, cells))
+    }
+}
+
+impl<T> Default for GridBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<(Coord, T)> for GridBuilder<T> {
+    fn extend<K: IntoIterator<Item = (Coord, T)>>(&mut self, iter: K) {
+        self.cells.extend(iter);
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for GridBuilder<T> {
+    fn from_iter<K: IntoIterator<Item = (Coord, T)>>(iter: K) -> Self {
+        let mut builder = GridBuilder::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_in_any_order() {
+        let builder: GridBuilder<char> =
+            [((1, 1), 'd'), ((0, 0), 'a'), ((0, 1), 'c'), ((1, 0), 'b')]
+                .iter()
+                .cloned()
+                .collect();
+
+        assert_eq!(
+            builder.build(),
+            Ok(Grid::new((2, 2), ['a', 'b', 'c', 'd'].to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_build_errors() {
+        let mut builder = GridBuilder::new();
+        builder.insert((1, 1), 'd').insert((0, 0), 'a');
+        assert_eq!(builder.build(), Err(GridBuildError::Gap((1, 0))));
+
+        let mut builder = GridBuilder::new();
+        builder.insert((0, 0), 'a').insert((0, 0), 'b');
+        assert_eq!(builder.build(), Err(GridBuildError::Duplicate((0, 0))));
+
+        let mut builder = GridBuilder::new().with_size((2, 2));
+        builder.insert((2, 0), 'a');
+        assert_eq!(builder.build(), Err(GridBuildError::OutOfBounds((2, 0))));
+    }
+
+    #[test]
+    fn test_build_with_fill() {
+        let mut builder = GridBuilder::new().with_size((3, 2));
+        builder.insert((2, 1), '#').insert((1, 0), '#');
+
+        assert_eq!(
+            builder.build_with_fill('.'),
+            Ok(Grid::new((3, 2), ['.', '#', '.', '.', '.', '#'].to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_build_empty() {
+        assert_eq!(
+            GridBuilder::<char>::new().build(),
+            Ok(Grid::new((0, 0), Vec::new()))
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use std::ops::{Index, IndexMut};
+
+use crate::Grid;
+
+pub type CoordN<const D: usize> = [usize; D];
+
+// Grid with `D` axes, cells are stored with the first axis varying fastest so `GridN<T, 2>` has
+// the same layout as `Grid<T>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridN<T, const D: usize> {
+    pub size: [usize; D],
+    cells: Vec<T>,
+}
+
+impl<T, const D: usize> GridN<T, D> {
+    pub fn new(size: [usize; D], cells: Vec<T>) -> Self {
+        assert_eq!(size.iter().product::<usize>(), cells.len());
+
+        GridN { size, cells }
+    }
+
+    pub fn from_fn<F>(size: [usize; D], mut f: F) -> Self
+    where
+        F: FnMut(CoordN<D>) -> T,
+    {
+        let cells = (0..size.iter().product())
+            .map(|index| f(Self::_coord(size, index)))
+            .collect();
+
+        GridN { size, cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        0 == self.len()
+    }
+
+    pub fn contains(&self, coord: CoordN<D>) -> bool {
+        coord.iter().zip(self.size.iter()).all(|(c, s)| c < s)
+    }
+
+    pub fn get(&self, coord: CoordN<D>) -> &T {
+        &self.cells[self.index(coord)]
+    }
+
+    pub fn get_mut(&mut self, coord: CoordN<D>) -> &mut T {
+        let index = self.index(coord);
+        &mut self.cells[index]
+    }
+
+    pub fn try_get(&self, coord: CoordN<D>) -> Option<&T> {
+        if self.contains(coord) {
+            Some(self.get(coord))
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, coord: CoordN<D>, value: T) {
+        let index = self.index(coord);
+        self.cells[index] = value;
+    }
+
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CoordN<D>, &T)> + '_ {
+        let size = self.size;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (Self::_coord(size, index), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (CoordN<D>, &mut T)> + '_ {
+        let size = self.size;
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, cell)| (Self::_coord(size, index), cell))
+    }
+
+    // Coordinates one step away along a single axis, or along any combination of axes
+    // (3^D - 1 of them) when `with_diagonals` is set
+    pub fn neighbor_coords(
+        &self,
+        coord: CoordN<D>,
+        with_diagonals: bool,
+    ) -> impl Iterator<Item = CoordN<D>> + '_ {
+        (0..3usize.pow(D as u32)).filter_map(move |offset_index| {
+            let mut neighbor = coord;
+            let mut moved_axes = 0;
+            let mut remaining = offset_index;
+
+            for (axis, value) in neighbor.iter_mut().enumerate() {
+                let offset = remaining % 3;
+                remaining /= 3;
+
+                match offset {
+                    0 if *value > 0 => *value -= 1,
+                    0 => return None,
+                    2 if *value + 1 < self.size[axis] => *value += 1,
+                    2 => return None,
+                    _ => continue,
+                }
+
+                moved_axes += 1;
+            }
+
+            match moved_axes {
+                0 => None,
+                1 => Some(neighbor),
+                _ if with_diagonals => Some(neighbor),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn neighbors_iter(
+        &self,
+        coord: CoordN<D>,
+        with_diagonals: bool,
+    ) -> impl Iterator<Item = (CoordN<D>, &T)> + '_ {
+        self.neighbor_coords(coord, with_diagonals)
+            .map(move |neighbor| (neighbor, self.get(neighbor)))
+    }
+
+    // Cells where `axis` equals `index`, as a grid with that axis removed (`E` must be `D - 1`)
+    pub fn slice<const E: usize>(&self, axis: usize, index: usize) -> GridN<T, E>
+    where
+        T: Clone,
+    {
+        assert_eq!(E + 1, D);
+        assert!(index < self.size[axis]);
+
+        let mut size = [0; E];
+        for (target, &source) in size.iter_mut().zip(
+            self.size
+                .iter()
+                .enumerate()
+                .filter(|&(a, _)| a != axis)
+                .map(|(_, s)| s),
+        ) {
+            *target = source;
+        }
+
+        GridN::from_fn(size, |coord| {
+            let mut full = [index; D];
+            for (target, &value) in full
+                .iter_mut()
+                .enumerate()
+                .filter(|&(a, _)| a != axis)
+                .map(|(_, target)| target)
+                .zip(coord.iter())
+            {
+                *target = value;
+            }
+
+            self.get(full).clone()
+        })
+    }
+
+    fn index(&self, coord: CoordN<D>) -> usize {
+        assert!(self.contains(coord));
+
+        coord
+            .iter()
+            .zip(self.size.iter())
+            .rev()
+            .fold(0, |index, (c, s)| index * s + c)
+    }
+
+    fn _coord(size: [usize; D], mut index: usize) -> CoordN<D> {
+        let mut coord = [0; D];
+
+        for (value, s) in coord.iter_mut().zip(size.iter()) {
+            *value = index % s;
+            index /= s;
+        }
+
+        coord
+    }
+}
+
+impl<T, const D: usize> Index<CoordN<D>> for GridN<T, D> {
+    type Output = T;
+
+    fn index(&self, coord: CoordN<D>) -> &T {
+        self.get(coord)
+    }
+}
+
+impl<T, const D: usize> IndexMut<CoordN<D>> for GridN<T, D> {
+    fn index_mut(&mut self, coord: CoordN<D>) -> &mut T {
+        self.get_mut(coord)
+    }
+}
+
+impl<T> From<Grid<T>> for GridN<T, 2> {
+    fn from(grid: Grid<T>) -> Self {
+        GridN::new([grid.size.0, grid.size.1], grid.into_cells())
+    }
+}
+
+impl<T> From<GridN<T, 2>> for Grid<T> {
+    fn from(grid: GridN<T, 2>) -> Self {
+        Grid::new((grid.size[0], grid.size[1]), grid.cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_cube() -> GridN<usize, 3> {
+        GridN::from_fn([3, 3, 3], |[x, y, z]| x + y * 10 + z * 100)
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut cube = create_cube();
+
+        assert_eq!(cube.len(), 27);
+        assert_eq!(cube.get([2, 1, 0]), &12);
+        assert_eq!(cube[[0, 2, 1]], 120);
+        assert_eq!(cube.try_get([3, 0, 0]), None);
+
+        cube.set([1, 1, 1], 0);
+        cube[[2, 2, 2]] += 1;
+
+        assert_eq!(cube[[1, 1, 1]], 0);
+        assert_eq!(cube[[2, 2, 2]], 223);
+        assert_eq!(cube.iter().nth(4), Some(([1, 1, 0], &11)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let cube = create_cube();
+
+        assert_eq!(cube.neighbor_coords([1, 1, 1], false).count(), 6);
+        assert_eq!(cube.neighbor_coords([1, 1, 1], true).count(), 26);
+        assert_eq!(cube.neighbor_coords([0, 0, 0], true).count(), 7);
+        assert_eq!(
+            cube.neighbors_iter([0, 0, 0], false)
+                .collect::<Vec<([usize; 3], &usize)>>(),
+            [([1, 0, 0], &1), ([0, 1, 0], &10), ([0, 0, 1], &100)].to_vec()
+        );
+
+        let hypercube: GridN<bool, 4> = GridN::from_fn([3, 3, 3, 3], |_| true);
+        assert_eq!(hypercube.neighbors_iter([1, 1, 1, 1], true).count(), 80);
+    }
+
+    #[test]
+    fn test_slice() {
+        let cube = create_cube();
+
+        assert_eq!(
+            cube.slice::<2>(2, 1),
+            GridN::from_fn([3, 3], |[x, y]| x + y * 10 + 100)
+        );
+        assert_eq!(
+            cube.slice::<2>(0, 2),
+            GridN::from_fn([3, 3], |[y, z]| 2 + y * 10 + z * 100)
+        );
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        let grid = Grid::new((3, 2), [0, 1, 2, 3, 4, 5].to_vec());
+        let grid_n = GridN::from(grid.clone());
+
+        assert_eq!(grid_n[[2, 1]], *grid.get((2, 1)));
+        assert_eq!(Grid::from(grid_n), grid);
+    }
+}
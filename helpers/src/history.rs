@@ -0,0 +1,335 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::{Coord, Grid, Rgb};
+
+// Clears the terminal and moves the cursor to the top left corner
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+// Literal codes written between two clear codes, low enough that GIF decoders never grow the
+// LZW code size past 9 bits
+const GIF_CODES_PER_CLEAR: usize = 250;
+
+// Frames recorded during a simulation, e.g. `history.record(automaton.grid())` after every step
+#[derive(Debug, Clone, PartialEq)]
+pub struct History<G> {
+    frames: Vec<G>,
+}
+
+impl<G> History<G> {
+    pub fn new() -> Self {
+        History { frames: Vec::new() }
+    }
+
+    pub fn record(&mut self, frame: &G)
+    where
+        G: Clone,
+    {
+        self.frames.push(frame.clone());
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&G> {
+        self.frames.get(index)
+    }
+
+    pub fn frames(&self) -> &[G] {
+        &self.frames
+    }
+}
+
+impl<G> Default for History<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> History<Grid<T>> {
+    // Coordinates that differ from the previous frame, every cell counts as changed in the first
+    // frame and when the size changed
+    pub fn changes(&self, index: usize) -> Vec<Coord> {
+        let frame = &self.frames[index];
+
+        match index.checked_sub(1).map(|previous| &self.frames[previous]) {
+            Some(previous) if previous.size == frame.size => frame
+                .iter()
+                .filter(|&(coord, cell)| previous.get(coord) != cell)
+                .map(|(coord, _)| coord)
+                .collect(),
+            _ => frame.iter().map(|(coord, _)| coord).collect(),
+        }
+    }
+
+    // Draws every frame in place, `render` gets the frame and its changed coordinates, e.g.
+    // `|grid, changes| grid.render_ansi(changes, color)`
+    pub fn replay<W, F>(&self, out: &mut W, fps: u32, render: F) -> io::Result<()>
+    where
+        W: Write,
+        F: Fn(&Grid<T>, &[Coord]) -> String,
+    {
+        let delay = Duration::from_secs(1) / fps.max(1);
+
+        for (index, frame) in self.frames.iter().enumerate() {
+            write!(
+                out,
+                "{}{}",
+                CLEAR_SCREEN,
+                render(frame, &self.changes(index))
+            )?;
+            out.flush()?;
+
+            thread::sleep(delay);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> History<Grid<T>> {
+    // Looping GIF with one pixel per cell, fails when the frames use more than 256 colors
+    pub fn to_gif<F>(&self, fps: u32, color: F) -> io::Result<Vec<u8>>
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let frames: Vec<((usize, usize), Vec<Rgb>)> = self
+            .frames
+            .iter()
+            .map(|frame| {
+                (
+                    frame.size,
+                    frame.iter().map(|(_, cell)| color(cell)).collect(),
+                )
+            })
+            .collect();
+
+        let mut palette: HashMap<Rgb, u8> = HashMap::new();
+        let mut colors: Vec<Rgb> = Vec::new();
+
+        for &rgb in frames.iter().flat_map(|(_, pixels)| pixels) {
+            if let Entry::Vacant(entry) = palette.entry(rgb) {
+                if colors.len() == 256 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "GIF frames can't use more than 256 colors",
+                    ));
+                }
+
+                entry.insert(colors.len() as u8);
+                colors.push(rgb);
+            }
+        }
+
+        colors.resize(256, (0, 0, 0));
+
+        let width = frames.iter().map(|(size, _)| size.0).max().unwrap_or(0);
+        let height = frames.iter().map(|(size, _)| size.1).max().unwrap_or(0);
+        let delay = (100 / fps.max(1)) as u16;
+
+        let mut output = b"GIF89a".to_vec();
+        output.extend_from_slice(&(width as u16).to_le_bytes());
+        output.extend_from_slice(&(height as u16).to_le_bytes());
+        // Global color table of 256 entries, 8 bit color resolution
+        output.extend_from_slice(&[0xf7, 0, 0]);
+
+        for (r, g, b) in colors {
+            output.extend_from_slice(&[r, g, b]);
+        }
+
+        // Netscape extension looping forever
+        output.extend_from_slice(&[0x21, 0xff, 0x0b]);
+        output.extend_from_slice(b"NETSCAPE2.0");
+        output.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+
+        for (size, pixels) in frames {
+            // Graphic control extension with the frame delay in hundredths of a second
+            output.extend_from_slice(&[0x21, 0xf9, 0x04, 0x00]);
+            output.extend_from_slice(&delay.to_le_bytes());
+            output.extend_from_slice(&[0x00, 0x00]);
+
+            output.extend_from_slice(&[0x2c, 0, 0, 0, 0]);
+            output.extend_from_slice(&(size.0 as u16).to_le_bytes());
+            output.extend_from_slice(&(size.1 as u16).to_le_bytes());
+            output.push(0);
+
+            let indices: Vec<u8> = pixels.iter().map(|rgb| palette[rgb]).collect();
+
+            output.push(8);
+            for block in lzw_uncompressed(&indices).chunks(255) {
+                output.push(block.len() as u8);
+                output.extend_from_slice(block);
+            }
+            output.push(0);
+        }
+
+        output.push(0x3b);
+
+        Ok(output)
+    }
+
+    pub fn write_gif<P, F>(&self, path: P, fps: u32, color: F) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+        F: Fn(&T) -> Rgb,
+    {
+        fs::write(path, self.to_gif(fps, color)?)
+    }
+
+    // One PNG per frame named `frame_0000.png`, `frame_0001.png`, ... inside `directory`
+    pub fn write_frames<P, F>(&self, directory: P, color: F) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+        F: Fn(&T) -> Rgb,
+    {
+        fs::create_dir_all(&directory)?;
+
+        for (index, frame) in self.frames.iter().enumerate() {
+            let path = directory.as_ref().join(format!("frame_{:04}.png", index));
+
+            frame.write_png(path, &[], &color)?;
+        }
+
+        Ok(())
+    }
+}
+
+// LZW stream with 8 bit literals only, every code is 9 bits wide
+fn lzw_uncompressed(indices: &[u8]) -> Vec<u8> {
+    const CLEAR: u16 = 256;
+    const END: u16 = 257;
+
+    let mut codes = Vec::with_capacity(indices.len() + indices.len() / GIF_CODES_PER_CLEAR + 2);
+
+    for chunk in indices.chunks(GIF_CODES_PER_CLEAR) {
+        codes.push(CLEAR);
+        codes.extend(chunk.iter().map(|&index| index as u16));
+    }
+
+    if codes.is_empty() {
+        codes.push(CLEAR);
+    }
+
+    codes.push(END);
+
+    let mut output = Vec::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+
+    for code in codes {
+        buffer |= (code as u32) << bits;
+        bits += 9;
+
+        while bits >= 8 {
+            output.push(buffer as u8);
+            buffer >>= 8;
+            bits -= 8;
+        }
+    }
+
+    if bits > 0 {
+        output.push(buffer as u8);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_history() -> History<Grid<usize>> {
+        let mut grid = Grid::new((2, 2), [0, 0, 0, 0].to_vec());
+        let mut history = History::new();
+
+        history.record(&grid);
+        grid.set((1, 0), 1);
+        history.record(&grid);
+        grid.set((1, 0), 2);
+        grid.set((0, 1), 2);
+        history.record(&grid);
+
+        history
+    }
+
+    #[test]
+    fn test_changes() {
+        let history = create_history();
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            history.changes(0),
+            [(0, 0), (1, 0), (0, 1), (1, 1)].to_vec()
+        );
+        assert_eq!(history.changes(1), [(1, 0)].to_vec());
+        assert_eq!(history.changes(2), [(1, 0), (0, 1)].to_vec());
+    }
+
+    #[test]
+    fn test_replay() {
+        let history = create_history();
+        let mut out = Vec::new();
+
+        history
+            .replay(&mut out, 1000, |grid, changes| {
+                grid.render_text(changes, |&cell, changed| {
+                    if changed {
+                        '*'
+                    } else {
+                        (b'0' + cell as u8) as char
+                    }
+                })
+            })
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            [
+                "\x1b[2J\x1b[H**\n**\n",
+                "\x1b[2J\x1b[H0*\n00\n",
+                "\x1b[2J\x1b[H0*\n*0\n",
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_to_gif() {
+        let history = create_history();
+        let gif = history
+            .to_gif(10, |&cell| (cell as u8 * 100, 0, 0))
+            .unwrap();
+
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], &[2, 0, 2, 0]);
+        // First palette entries in order of appearance
+        assert_eq!(&gif[13..22], &[0, 0, 0, 100, 0, 0, 200, 0, 0]);
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        let noisy = History {
+            frames: [Grid::from_fn((32, 32), |(x, y)| x + y * 32)].to_vec(),
+        };
+
+        assert!(noisy
+            .to_gif(10, |&cell| ((cell % 256) as u8, (cell / 256) as u8, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_lzw_uncompressed() {
+        // Clear (256), 1, 2 and end (257) as 9 bit codes packed from the least significant bit
+        assert_eq!(
+            lzw_uncompressed(&[1, 2]),
+            [0x00, 0x03, 0x08, 0x08, 0x08].to_vec()
+        );
+    }
+}
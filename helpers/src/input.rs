@@ -1,4 +1,7 @@
-use crate::Grid;
+use crate::input_error::DebugError;
+use crate::{Grid, InputError, ParseError};
+use std::convert::Infallible;
+use std::error::Error;
 use std::fmt::Debug;
 use std::iter::FromIterator;
 use std::str::FromStr;
@@ -10,7 +13,7 @@ where
     input
         .lines()
         .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
+        .filter(|line| !line.is_empty())
         .collect()
 }
 
@@ -21,31 +24,115 @@ where
     input
         .split(pattern)
         .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
+        .filter(|line| !line.is_empty())
         .collect()
 }
 
-pub fn input_grid<'a, R>(input: &'a str) -> Grid<R>
+pub fn input_grid<R>(input: &str) -> Grid<R>
 where
-    R: FromStr + Clone,
+    R: FromStr,
     <R as FromStr>::Err: Debug,
 {
-    let lines: Vec<&str> = input
+    try_grid_cells(input, char_tokens, |token| {
+        token.parse().map_err(DebugError::new)
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+// One cell per character
+pub fn try_input_grid<R>(input: &str) -> Result<Grid<R>, InputError>
+where
+    R: FromStr,
+    R::Err: Error + Send + Sync + 'static,
+{
+    try_grid_cells(input, char_tokens, |token| token.parse())
+}
+
+pub fn input_grid_with<T, F>(input: &str, cell: F) -> Grid<T>
+where
+    F: Fn(char) -> T,
+{
+    try_input_grid_with(input, cell).unwrap_or_else(|error| panic!("{}", error))
+}
+
+// One cell per character mapped by `cell`, e.g. `|char| char == '#'`
+pub fn try_input_grid_with<T, F>(input: &str, cell: F) -> Result<Grid<T>, InputError>
+where
+    F: Fn(char) -> T,
+{
+    try_grid_cells(input, char_tokens, |token| {
+        Ok::<T, Infallible>(cell(token.chars().next().unwrap()))
+    })
+}
+
+pub fn input_grid_tokens<R>(input: &str) -> Grid<R>
+where
+    R: FromStr,
+    <R as FromStr>::Err: Debug,
+{
+    try_grid_cells(input, whitespace_tokens, |token| {
+        token.parse().map_err(DebugError::new)
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+// One cell per whitespace separated token, e.g. rows of numbers
+pub fn try_input_grid_tokens<R>(input: &str) -> Result<Grid<R>, InputError>
+where
+    R: FromStr,
+    R::Err: Error + Send + Sync + 'static,
+{
+    try_grid_cells(input, whitespace_tokens, |token| token.parse())
+}
+
+fn try_grid_cells<'a, T, E, S, P>(
+    input: &'a str,
+    tokens: S,
+    parse: P,
+) -> Result<Grid<T>, InputError>
+where
+    E: Error + Send + Sync + 'static,
+    S: Fn(&'a str) -> Vec<&'a str>,
+    P: Fn(&'a str) -> Result<T, E>,
+{
+    let rows: Vec<(&str, Vec<&str>)> = input
         .lines()
         .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
+        .filter(|line| !line.is_empty())
+        .map(|line| (line, tokens(line)))
         .collect();
 
-    let size = (lines[0].len(), lines.len());
-    let mut data: Vec<R> = Vec::with_capacity(size.0 * size.1);
+    let size = (rows.first().map_or(0, |(_, row)| row.len()), rows.len());
+    let mut data: Vec<T> = Vec::with_capacity(size.0 * size.1);
+
+    for (line, row) in rows {
+        if row.len() != size.0 {
+            return Err(InputError::at(
+                input,
+                line,
+                ParseError::RaggedRow {
+                    expected: size.0,
+                    found: row.len(),
+                },
+            ));
+        }
 
-    for line in lines {
-        for element in line.split("").filter(|&x| !x.is_empty()) {
-            data.push(element.parse().unwrap());
+        for token in row {
+            data.push(parse(token).map_err(|error| InputError::value(input, token, error))?);
         }
     }
 
-    Grid::new(size, data)
+    Ok(Grid::new(size, data))
+}
+
+fn char_tokens(line: &str) -> Vec<&str> {
+    line.char_indices()
+        .map(|(index, char)| &line[index..index + char.len_utf8()])
+        .collect()
+}
+
+fn whitespace_tokens(line: &str) -> Vec<&str> {
+    line.split_whitespace().collect()
 }
 
 pub fn parse_split_input<T: FromStr, R>(input: &str, pattern: &str) -> R
@@ -53,12 +140,18 @@ where
     T::Err: Debug,
     R: FromIterator<T>,
 {
-    input
-        .split(pattern)
-        .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
-        .map(|line| line.parse().unwrap())
-        .collect()
+    try_parse_lines(input, input.split(pattern), |line| {
+        line.parse().map_err(DebugError::new)
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn try_parse_split_input<T: FromStr, R>(input: &str, pattern: &str) -> Result<R, InputError>
+where
+    T::Err: Error + Send + Sync + 'static,
+    R: FromIterator<T>,
+{
+    try_parse_lines(input, input.split(pattern), |line| line.parse())
 }
 
 pub fn parse_input<T: FromStr, R>(input: &str) -> R
@@ -66,11 +159,31 @@ where
     T::Err: Debug,
     R: FromIterator<T>,
 {
-    input
-        .lines()
+    try_parse_lines(input, input.lines(), |line| {
+        line.parse().map_err(DebugError::new)
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn try_parse_input<T: FromStr, R>(input: &str) -> Result<R, InputError>
+where
+    T::Err: Error + Send + Sync + 'static,
+    R: FromIterator<T>,
+{
+    try_parse_lines(input, input.lines(), |line| line.parse())
+}
+
+fn try_parse_lines<'a, T, E, R, L, P>(input: &'a str, lines: L, parse: P) -> Result<R, InputError>
+where
+    E: Error + Send + Sync + 'static,
+    R: FromIterator<T>,
+    L: Iterator<Item = &'a str>,
+    P: Fn(&'a str) -> Result<T, E>,
+{
+    lines
         .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
-        .map(|line| line.parse().unwrap())
+        .filter(|line| !line.is_empty())
+        .map(|line| parse(line).map_err(|error| InputError::value(input, line, error)))
         .collect()
 }
 
@@ -210,4 +323,144 @@ qrst
         let expected: HashSet<u64> = [1721, 979, 366, 299, 675, 1456].iter().cloned().collect();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_try_parse_input_error() {
+        let input = "
+            1721
+            97x9
+        ";
+
+        let error = try_parse_input::<u64, Vec<u64>>(input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 13));
+        assert_eq!(error.text, "97x9");
+        assert!(matches!(
+            &error.source,
+            ParseError::Value(source)
+                if source.downcast_ref() == Some(&"97x9".parse::<u64>().unwrap_err())
+        ));
+        assert!(error.source().is_none());
+        assert_eq!(
+            error.to_string(),
+            [
+                "invalid input \"97x9\" at line 3, column 13: invalid digit found in string",
+                "  |",
+                "3 |             97x9",
+                "  |             ^^^^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_try_parse_split_input_error() {
+        let input = "1,2,,x,4";
+
+        let error = try_parse_split_input::<u8, Vec<u8>>(input, ",").unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 6, "x"));
+        assert_eq!(
+            try_parse_split_input::<u8, Vec<u8>>("1,2,,3", ",").unwrap(),
+            [1, 2, 3].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_try_input_grid_error() {
+        let input = "123\n4a6\n";
+
+        let error = try_input_grid::<u8>(input).unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "a"));
+        assert!(matches!(
+            &error.source,
+            ParseError::Value(source)
+                if source.downcast_ref() == Some(&"a".parse::<u8>().unwrap_err())
+        ));
+        assert_eq!(
+            try_input_grid::<u8>("12\n34").unwrap(),
+            Grid::new((2, 2), [1, 2, 3, 4].to_vec())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at line 1, column 1")]
+    fn test_parse_input_panics_with_location() {
+        parse_input::<u8, Vec<u8>>("y");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid input \"b\" at line 2, column 1: ()")]
+    fn test_parse_input_debug_error() {
+        struct Letter;
+
+        impl FromStr for Letter {
+            type Err = ();
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                match input {
+                    "a" => Ok(Letter),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        parse_input::<Letter, Vec<Letter>>("a\nb");
+    }
+
+    #[test]
+    fn test_input_grid_with() {
+        let input = "
+            #.
+            .#
+        ";
+
+        assert_eq!(
+            input_grid_with(input, |char| char == '#'),
+            Grid::new((2, 2), [true, false, false, true].to_vec())
+        );
+    }
+
+    #[test]
+    fn test_input_grid_tokens() {
+        let input = "
+            22 13 17
+             8  2 23
+        ";
+
+        assert_eq!(
+            input_grid_tokens::<u8>(input),
+            Grid::new((3, 2), [22, 13, 17, 8, 2, 23].to_vec())
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let error = try_input_grid_with("###\n##\n###", |char| char).unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 1, "##")
+        );
+        assert!(matches!(
+            error.source,
+            ParseError::RaggedRow {
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            [
+                "invalid input \"##\" at line 2, column 1: expected 3 cells in the row, found 2",
+                "  |",
+                "2 | ##",
+                "  | ^^",
+            ]
+            .join("\n")
+        );
+
+        assert!(try_input_grid_tokens::<u8>("1 2\n3 4 5").is_err());
+    }
 }
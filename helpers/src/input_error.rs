@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+// Failure of any parser in this crate at `text`, with its 1-based position in the input and the
+// line holding it
+#[derive(Debug)]
+pub struct InputError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub line_text: String,
+    pub source: ParseError,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    // `FromStr` error of the located text, e.g. a `ParseIntError` to get back with `downcast_ref`
+    Value(Box<dyn Error + Send + Sync>),
+    // Grid row with a different number of cells than the first one
+    RaggedRow { expected: usize, found: usize },
+    MissingSection,
+    // Section left over after every part was parsed
+    UnexpectedSection,
+    // Literal text of a `scan!` pattern that wasn't found
+    Mismatch(String),
+    // Text left after the end of a `scan!` pattern
+    Trailing,
+    // Number of integers found when a fixed amount was expected
+    Count { expected: usize, found: usize },
+}
+
+impl InputError {
+    // `text` must be a slice of `input`, as handed out by `lines`, `split`, `trim`...
+    pub(crate) fn at(input: &str, text: &str, source: ParseError) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= input.len())
+            .expect("error text must be a slice of the input");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+
+        InputError {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: text.to_string(),
+            line_text: input[line_start..].lines().next().unwrap_or("").to_string(),
+            source,
+        }
+    }
+
+    pub(crate) fn value<E>(input: &str, text: &str, error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Self::at(input, text, ParseError::Value(Box::new(error)))
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = self.text.lines().next().unwrap_or("").chars().count();
+
+        writeln!(
+            f,
+            "invalid input {:?} at line {}, column {}: {}",
+            self.text, self.line, self.column, self.source
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.line_text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(underline.max(1))
+        )
+    }
+}
+
+// No `source`, the message already ends with it and chain reporters would print it again
+impl Error for InputError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Value(error) => write!(f, "{}", error),
+            ParseError::RaggedRow { expected, found } => {
+                write!(f, "expected {} cells in the row, found {}", expected, found)
+            }
+            ParseError::MissingSection => write!(f, "missing section"),
+            ParseError::UnexpectedSection => write!(f, "unexpected section"),
+            ParseError::Mismatch(literal) => write!(f, "expected {:?}", literal),
+            ParseError::Trailing => write!(f, "unexpected text after the pattern"),
+            ParseError::Count { expected, found } => {
+                write!(f, "expected {} integers, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Stands in for parse errors that only implement `Debug`, like `()`, so the panicking parsers
+// still report where the input is wrong
+#[derive(Debug)]
+pub(crate) struct DebugError(String);
+
+impl DebugError {
+    pub(crate) fn new<E: Debug>(error: E) -> Self {
+        DebugError(format!("{:?}", error))
+    }
+}
+
+impl Display for DebugError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for DebugError {}
@@ -0,0 +1,178 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::{InputError, ParseError};
+
+// Every run of digits in `line`, anything else is a separator: "1-3 a: 10" gives 1, 3 and 10
+pub fn ints<T>(line: &str) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_ints(line).unwrap_or_else(|error| panic!("{}", error))
+}
+
+// Like `ints`, but a `-` right before the digits makes them negative unless it follows another
+// number, so "x=-10..-5" gives -10 and -5 while "1-3" still gives 1 and 3
+pub fn signed_ints<T>(line: &str) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_signed_ints(line).unwrap_or_else(|error| panic!("{}", error))
+}
+
+// Fails on numbers that don't fit in `T`, e.g. `try_ints::<u8>("300")`
+pub fn try_ints<T>(line: &str) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_extract(line, false)
+}
+
+pub fn try_signed_ints<T>(line: &str) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_extract(line, true)
+}
+
+pub fn ints_n<T, const N: usize>(line: &str) -> [T; N]
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_ints_n(line).unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn signed_ints_n<T, const N: usize>(line: &str) -> [T; N]
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_signed_ints_n(line).unwrap_or_else(|error| panic!("{}", error))
+}
+
+// Exactly `N` integers, e.g. `let [x1, y1, x2, y2] = try_ints_n(line)?`
+pub fn try_ints_n<T, const N: usize>(line: &str) -> Result<[T; N], InputError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_extract(line, false).and_then(|ints| to_array(line, ints))
+}
+
+pub fn try_signed_ints_n<T, const N: usize>(line: &str) -> Result<[T; N], InputError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    try_extract(line, true).and_then(|ints| to_array(line, ints))
+}
+
+fn try_extract<T>(line: &str, signed: bool) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    let bytes = line.as_bytes();
+    let mut ints = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let negative = signed
+            && index > 0
+            && bytes[index - 1] == b'-'
+            && (index < 2 || !bytes[index - 2].is_ascii_digit());
+        let start = if negative { index - 1 } else { index };
+
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        let text = &line[start..index];
+        ints.push(
+            text.parse()
+                .map_err(|error| InputError::value(line, text, error))?,
+        );
+    }
+
+    Ok(ints)
+}
+
+fn to_array<T, const N: usize>(line: &str, ints: Vec<T>) -> Result<[T; N], InputError> {
+    let found = ints.len();
+
+    <[T; N]>::try_from(ints)
+        .map_err(|_| InputError::at(line, line, ParseError::Count { expected: N, found }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<u32>("1-3 a: 10"), [1, 3, 10].to_vec());
+        assert_eq!(ints::<u8>("no numbers"), Vec::<u8>::new());
+        assert_eq!(
+            ints::<usize>("22 13  17 11   0"),
+            [22, 13, 17, 11, 0].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_signed_ints() {
+        assert_eq!(
+            signed_ints::<i32>("target area: x=20..30, y=-10..-5"),
+            [20, 30, -10, -5].to_vec()
+        );
+        assert_eq!(signed_ints::<i32>("1-3, -4"), [1, 3, -4].to_vec());
+        assert_eq!(signed_ints::<i32>("-7"), [-7].to_vec());
+    }
+
+    #[test]
+    fn test_ints_n() {
+        let [x1, y1, x2, y2] = ints_n::<usize, 4>("0,9 -> 5,9");
+        assert_eq!((x1, y1, x2, y2), (0, 9, 5, 9));
+
+        assert_eq!(signed_ints_n::<isize, 2>("x=-1, y=2"), [-1, 2]);
+    }
+
+    #[test]
+    fn test_ints_errors() {
+        let error = try_ints_n::<u8, 3>("1,2").unwrap_err();
+        assert!(matches!(
+            error.source,
+            ParseError::Count {
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            [
+                "invalid input \"1,2\" at line 1, column 1: expected 3 integers, found 2",
+                "  |",
+                "1 | 1,2",
+                "  | ^^^",
+            ]
+            .join("\n")
+        );
+
+        let error = try_signed_ints_n::<i8, 2>("a=1, b=-300").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (8, "-300"));
+
+        let error = try_ints::<u8>("1 10 100 1000").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (10, "1000"));
+        assert_eq!(try_signed_ints::<i8>("-1 -2").unwrap(), [-1, -2].to_vec());
+    }
+}
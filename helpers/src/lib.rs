@@ -1,9 +1,54 @@
+pub use automaton::*;
+pub use bit_grid::*;
+pub use debug::*;
+#[cfg(feature = "petgraph")]
+pub use graph::*;
 pub use grid::*;
+pub use grid_builder::*;
+pub use grid_n::*;
+pub use history::*;
 pub use input::*;
+pub use input_error::*;
+pub use ints::*;
+pub use lines::*;
+pub use neighborhood::*;
+pub use ocr::*;
+pub use pathfinding::*;
+pub use prefix_sums::*;
+pub use regions::*;
+pub use render::*;
 pub use run::*;
+pub use scan::*;
+pub use sections::*;
+pub use sparse_grid::*;
+pub use tiled_grid::*;
+pub use window::*;
 
+mod automaton;
+mod bit_grid;
 mod debug;
+#[cfg(feature = "petgraph")]
+mod graph;
 mod grid;
+mod grid_builder;
+mod grid_n;
+mod history;
 mod input;
+mod input_error;
 mod instant;
+mod ints;
+mod lines;
+mod neighborhood;
+mod ocr;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod pathfinding;
+mod prefix_sums;
+mod regions;
+mod render;
 mod run;
+mod scan;
+mod sections;
+mod sparse_grid;
+mod tiled_grid;
+mod window;
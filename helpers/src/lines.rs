@@ -0,0 +1,246 @@
+use crate::{Coord, Direction, Grid, SignedCoord, SparseGrid};
+
+// Bresenham line, both ends included
+pub struct Line {
+    current: SignedCoord,
+    to: SignedCoord,
+    step: (isize, isize),
+    delta: (isize, isize),
+    error: isize,
+    finished: bool,
+}
+
+pub fn line(from: SignedCoord, to: SignedCoord) -> Line {
+    let delta = ((to.0 - from.0).abs(), -(to.1 - from.1).abs());
+
+    Line {
+        current: from,
+        to,
+        step: ((to.0 - from.0).signum(), (to.1 - from.1).signum()),
+        delta,
+        error: delta.0 + delta.1,
+        finished: false,
+    }
+}
+
+impl Iterator for Line {
+    type Item = SignedCoord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let point = self.current;
+
+        if point == self.to {
+            self.finished = true;
+            return Some(point);
+        }
+
+        let double_error = 2 * self.error;
+
+        if double_error >= self.delta.1 {
+            self.error += self.delta.1;
+            self.current.0 += self.step.0;
+        }
+
+        if double_error <= self.delta.0 {
+            self.error += self.delta.0;
+            self.current.1 += self.step.1;
+        }
+
+        Some(point)
+    }
+}
+
+// Endless walk away from `from` (excluded) in `direction`
+pub fn ray(from: SignedCoord, direction: Direction) -> impl Iterator<Item = SignedCoord> {
+    let (dx, dy) = direction.offset();
+
+    (1..).map(move |step| (from.0 + dx * step, from.1 + dy * step))
+}
+
+impl<T> Grid<T> {
+    pub fn line(&self, from: Coord, to: Coord) -> impl Iterator<Item = Coord> {
+        assert!(self.contains(from));
+        assert!(self.contains(to));
+
+        line(signed(from), signed(to)).map(|(x, y)| (x as usize, y as usize))
+    }
+
+    // Cells from `from` (excluded) in `direction` until the edge of the grid
+    pub fn ray(&self, from: Coord, direction: Direction) -> impl Iterator<Item = Coord> + '_ {
+        ray(signed(from), direction)
+            .take_while(move |&(x, y)| x >= 0 && y >= 0 && self.contains((x as usize, y as usize)))
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    // Like `ray`, but also stops after the first cell for which `blocks` holds
+    pub fn ray_until<'a, P>(
+        &'a self,
+        from: Coord,
+        direction: Direction,
+        blocks: P,
+    ) -> impl Iterator<Item = Coord> + 'a
+    where
+        P: Fn(Coord, &T) -> bool + 'a,
+    {
+        let mut blocked = false;
+
+        self.ray(from, direction).take_while(move |&coord| {
+            let visible = !blocked;
+            blocked = blocked || blocks(coord, self.get(coord));
+
+            visible
+        })
+    }
+
+    // Cells along the rays in every direction up to and including the first blocking cell
+    pub fn visible_from<'a, P>(
+        &'a self,
+        from: Coord,
+        with_diagonals: bool,
+        blocks: P,
+    ) -> impl Iterator<Item = Coord> + 'a
+    where
+        P: Fn(Coord, &T) -> bool + Copy + 'a,
+    {
+        Direction::ALL
+            .iter()
+            .filter(move |direction| with_diagonals || !direction.is_diagonal())
+            .flat_map(move |&direction| self.ray_until(from, direction, blocks))
+    }
+}
+
+impl<T> SparseGrid<T> {
+    // Cells from `from` (excluded) in `direction` until leaving the bounds
+    pub fn ray(
+        &self,
+        from: SignedCoord,
+        direction: Direction,
+    ) -> impl Iterator<Item = SignedCoord> + '_ {
+        let bounds = self.bounds().unwrap_or(((0, 0), (-1, -1)));
+
+        ray(from, direction).take_while(move |&(x, y)| {
+            x >= (bounds.0).0 && x <= (bounds.1).0 && y >= (bounds.0).1 && y <= (bounds.1).1
+        })
+    }
+
+    // Like `ray`, but also stops after the first present cell for which `blocks` holds
+    pub fn ray_until<'a, P>(
+        &'a self,
+        from: SignedCoord,
+        direction: Direction,
+        blocks: P,
+    ) -> impl Iterator<Item = SignedCoord> + 'a
+    where
+        P: Fn(SignedCoord, &T) -> bool + 'a,
+    {
+        let mut blocked = false;
+
+        self.ray(from, direction).take_while(move |&coord| {
+            let visible = !blocked;
+            blocked = blocked || self.get(coord).is_some_and(|cell| blocks(coord, cell));
+
+            visible
+        })
+    }
+
+    // Cells along the rays in every direction up to and including the first blocking cell
+    pub fn visible_from<'a, P>(
+        &'a self,
+        from: SignedCoord,
+        with_diagonals: bool,
+        blocks: P,
+    ) -> impl Iterator<Item = SignedCoord> + 'a
+    where
+        P: Fn(SignedCoord, &T) -> bool + Copy + 'a,
+    {
+        Direction::ALL
+            .iter()
+            .filter(move |direction| with_diagonals || !direction.is_diagonal())
+            .flat_map(move |&direction| self.ray_until(from, direction, blocks))
+    }
+}
+
+fn signed(coord: Coord) -> SignedCoord {
+    (coord.0 as isize, coord.1 as isize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        assert_eq!(
+            line((0, 0), (3, 0)).collect::<Vec<SignedCoord>>(),
+            [(0, 0), (1, 0), (2, 0), (3, 0)].to_vec()
+        );
+        assert_eq!(
+            line((2, 2), (0, 0)).collect::<Vec<SignedCoord>>(),
+            [(2, 2), (1, 1), (0, 0)].to_vec()
+        );
+        assert_eq!(
+            line((0, 0), (4, -2)).collect::<Vec<SignedCoord>>(),
+            [(0, 0), (1, -1), (2, -1), (3, -2), (4, -2)].to_vec()
+        );
+        assert_eq!(
+            line((1, 1), (1, 1)).collect::<Vec<SignedCoord>>(),
+            [(1, 1)].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_grid_rays() {
+        let grid = Grid::new(
+            (4, 3),
+            [
+                '.', '.', '#', '.', //
+                '.', '.', '.', '.', //
+                '#', '.', '.', '.', //
+            ]
+            .to_vec(),
+        );
+
+        assert_eq!(
+            grid.ray((1, 0), Direction::Right).collect::<Vec<Coord>>(),
+            [(2, 0), (3, 0)].to_vec()
+        );
+        assert_eq!(
+            grid.ray_until((0, 0), Direction::Right, |_, &cell| cell == '#')
+                .collect::<Vec<Coord>>(),
+            [(1, 0), (2, 0)].to_vec()
+        );
+        assert_eq!(
+            grid.visible_from((1, 1), false, |_, &cell| cell == '#')
+                .collect::<Vec<Coord>>(),
+            [(0, 1), (1, 0), (2, 1), (3, 1), (1, 2)].to_vec()
+        );
+        assert_eq!(
+            grid.visible_from((0, 0), true, |_, &cell| cell == '#')
+                .collect::<Vec<Coord>>(),
+            [(1, 0), (2, 0), (1, 1), (2, 2), (0, 1), (0, 2)].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_sparse_grid_rays() {
+        let grid: SparseGrid<char> = [((-2, 0), '#'), ((2, 0), '#'), ((0, -1), 'o')]
+            .iter()
+            .cloned()
+            .collect();
+
+        assert_eq!(
+            grid.ray((0, 0), Direction::Left)
+                .collect::<Vec<SignedCoord>>(),
+            [(-1, 0), (-2, 0)].to_vec()
+        );
+        assert_eq!(
+            grid.visible_from((1, 0), false, |_, &cell| cell == '#')
+                .collect::<Vec<SignedCoord>>(),
+            [(0, 0), (-1, 0), (-2, 0), (1, -1), (2, 0)].to_vec()
+        );
+    }
+}
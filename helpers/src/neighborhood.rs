@@ -0,0 +1,303 @@
+use crate::Coord;
+
+pub type Offset = (isize, isize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    TopLeft,
+    Top,
+    TopRight,
+    Right,
+    BottomRight,
+    Bottom,
+    BottomLeft,
+}
+
+impl Direction {
+    // Clockwise starting from the left, the order `Grid::neighbors_iter` has always used
+    pub const ALL: [Direction; 8] = [
+        Direction::Left,
+        Direction::TopLeft,
+        Direction::Top,
+        Direction::TopRight,
+        Direction::Right,
+        Direction::BottomRight,
+        Direction::Bottom,
+        Direction::BottomLeft,
+    ];
+
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Left,
+        Direction::Top,
+        Direction::Right,
+        Direction::Bottom,
+    ];
+
+    pub const fn offset(self) -> Offset {
+        match self {
+            Direction::Left => (-1, 0),
+            Direction::TopLeft => (-1, -1),
+            Direction::Top => (0, -1),
+            Direction::TopRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::BottomRight => (1, 1),
+            Direction::Bottom => (0, 1),
+            Direction::BottomLeft => (-1, 1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let (dx, dy) = self.offset();
+
+        dx != 0 && dy != 0
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    fn rotate(self, steps: usize) -> Self {
+        let index = Direction::ALL.iter().position(|&d| d == self).unwrap();
+
+        Direction::ALL[(index + steps) % 8]
+    }
+}
+
+pub const KNIGHT_MOVES: [Offset; 8] = [
+    (-2, -1),
+    (-1, -2),
+    (1, -2),
+    (2, -1),
+    (2, 1),
+    (1, 2),
+    (-1, 2),
+    (-2, 1),
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Neighborhood<'a> {
+    // Left, top, right and bottom
+    VonNeumann,
+    // All eight surrounding cells
+    Moore,
+    // Every cell in the (2N+1)x(2N+1) square around the center, in row-major order
+    Radius(usize),
+    Custom(&'a [Offset]),
+}
+
+impl<'a> Neighborhood<'a> {
+    pub fn from_diagonals(with_diagonals: bool) -> Self {
+        if with_diagonals {
+            Neighborhood::Moore
+        } else {
+            Neighborhood::VonNeumann
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Neighborhood::VonNeumann => 4,
+            Neighborhood::Moore => 8,
+            Neighborhood::Radius(radius) => (2 * radius + 1) * (2 * radius + 1) - 1,
+            Neighborhood::Custom(offsets) => offsets.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        0 == self.len()
+    }
+
+    pub fn offset(&self, index: usize) -> Option<Offset> {
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(match self {
+            Neighborhood::VonNeumann => Direction::CARDINAL[index].offset(),
+            Neighborhood::Moore => Direction::ALL[index].offset(),
+            Neighborhood::Radius(radius) => {
+                let side = 2 * radius + 1;
+                // Skip the center cell
+                let index = if index >= side * side / 2 {
+                    index + 1
+                } else {
+                    index
+                };
+
+                (
+                    (index % side) as isize - *radius as isize,
+                    (index / side) as isize - *radius as isize,
+                )
+            }
+            Neighborhood::Custom(offsets) => offsets[index],
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Edges {
+    // Neighbors outside the grid are skipped
+    Bounded,
+    // Neighbors outside the grid wrap around to the opposite edge, on grids too small for the
+    // neighborhood the cell itself is skipped and every wrapped cell is only returned once
+    Toroidal,
+}
+
+pub struct NeighborCoordIter<'a> {
+    size: (usize, usize),
+    coord: Coord,
+    neighborhood: Neighborhood<'a>,
+    edges: Edges,
+    index: usize,
+}
+
+impl<'a> NeighborCoordIter<'a> {
+    pub fn new(
+        size: (usize, usize),
+        coord: Coord,
+        neighborhood: Neighborhood<'a>,
+        edges: Edges,
+    ) -> Self {
+        NeighborCoordIter {
+            size,
+            coord,
+            neighborhood,
+            edges,
+            index: 0,
+        }
+    }
+
+    fn apply(&self, offset: Offset) -> Option<Coord> {
+        let x = self.coord.0 as isize + offset.0;
+        let y = self.coord.1 as isize + offset.1;
+
+        match self.edges {
+            Edges::Bounded => {
+                if x < 0 || y < 0 || x >= self.size.0 as isize || y >= self.size.1 as isize {
+                    None
+                } else {
+                    Some((x as usize, y as usize))
+                }
+            }
+            Edges::Toroidal => Some((
+                x.rem_euclid(self.size.0 as isize) as usize,
+                y.rem_euclid(self.size.1 as isize) as usize,
+            )),
+        }
+    }
+
+    // Whether an earlier offset already wrapped around to `coord`
+    fn seen(&self, coord: Coord, index: usize) -> bool {
+        (0..index)
+            .filter_map(|previous| self.neighborhood.offset(previous))
+            .any(|offset| self.apply(offset) == Some(coord))
+    }
+}
+
+impl<'a> Iterator for NeighborCoordIter<'a> {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(offset) = self.neighborhood.offset(self.index) {
+            let index = self.index;
+            self.index += 1;
+
+            match self.apply(offset) {
+                Some(coord) if self.edges == Edges::Bounded => return Some(coord),
+                Some(coord) if coord != self.coord && !self.seen(coord, index) => {
+                    return Some(coord)
+                }
+                _ => continue,
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::Top.turn_right(), Direction::Right);
+        assert_eq!(Direction::Top.turn_left(), Direction::Left);
+        assert_eq!(Direction::BottomLeft.opposite(), Direction::TopRight);
+        assert!(Direction::TopLeft.is_diagonal());
+        assert!(!Direction::Bottom.is_diagonal());
+    }
+
+    #[test]
+    fn test_radius_offsets() {
+        let neighborhood = Neighborhood::Radius(2);
+
+        assert_eq!(neighborhood.len(), 24);
+        assert_eq!(neighborhood.offset(0), Some((-2, -2)));
+        assert_eq!(neighborhood.offset(11), Some((-1, 0)));
+        assert_eq!(neighborhood.offset(12), Some((1, 0)));
+        assert_eq!(neighborhood.offset(23), Some((2, 2)));
+        assert_eq!(neighborhood.offset(24), None);
+    }
+
+    #[test]
+    fn test_custom_neighborhood() {
+        let coords: Vec<Coord> = NeighborCoordIter::new(
+            (5, 5),
+            (0, 0),
+            Neighborhood::Custom(&KNIGHT_MOVES),
+            Edges::Bounded,
+        )
+        .collect();
+
+        assert_eq!(coords, [(2, 1), (1, 2)].to_vec());
+    }
+
+    #[test]
+    fn test_toroidal_edges() {
+        let coords: Vec<Coord> =
+            NeighborCoordIter::new((4, 5), (0, 0), Neighborhood::Moore, Edges::Toroidal).collect();
+
+        assert_eq!(
+            coords,
+            [
+                (3, 0),
+                (3, 4),
+                (0, 4),
+                (1, 4),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (3, 1)
+            ]
+            .to_vec()
+        );
+    }
+
+    #[test]
+    fn test_toroidal_small_grids() {
+        let coords: Vec<Coord> =
+            NeighborCoordIter::new((1, 3), (0, 1), Neighborhood::VonNeumann, Edges::Toroidal)
+                .collect();
+
+        assert_eq!(coords, [(0, 0), (0, 2)].to_vec());
+
+        let coords: Vec<Coord> =
+            NeighborCoordIter::new((2, 2), (0, 0), Neighborhood::Moore, Edges::Toroidal).collect();
+
+        assert_eq!(coords, [(1, 0), (1, 1), (0, 1)].to_vec());
+        assert_eq!(
+            NeighborCoordIter::new((1, 1), (0, 0), Neighborhood::Moore, Edges::Toroidal).count(),
+            0
+        );
+    }
+}
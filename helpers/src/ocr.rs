@@ -0,0 +1,284 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::{Coord, Grid};
+
+const SMALL_FONT_HEIGHT: usize = 6;
+const LARGE_FONT_HEIGHT: usize = 10;
+
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    // Height of the lit area, only 6 and 10 rows tall letters are known
+    UnsupportedHeight(usize),
+    // Index in the text and first column of every glyph that didn't match a letter
+    UnrecognizedGlyphs(Vec<(usize, usize)>),
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => write!(
+                f,
+                "letters must be {} or {} rows tall, found {}",
+                SMALL_FONT_HEIGHT, LARGE_FONT_HEIGHT, height
+            ),
+            OcrError::UnrecognizedGlyphs(glyphs) => write!(
+                f,
+                "unrecognized glyphs: {}",
+                glyphs
+                    .iter()
+                    .map(|(index, column)| format!("#{} at column {}", index, column))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+// Reads block letters, the lit area is trimmed first so the text may sit anywhere in the grid
+pub fn ocr(grid: &Grid<bool>) -> Result<String, OcrError> {
+    let lit_rows: Vec<usize> = grid
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().any(|&lit| lit))
+        .map(|(y, _)| y)
+        .collect();
+
+    let (top, bottom) = match (lit_rows.first(), lit_rows.last()) {
+        (Some(&top), Some(&bottom)) => (top, bottom),
+        _ => return Ok(String::new()),
+    };
+
+    let height = bottom - top + 1;
+    let font: &[(char, &str)] = match height {
+        SMALL_FONT_HEIGHT => &SMALL_FONT,
+        LARGE_FONT_HEIGHT => &LARGE_FONT,
+        _ => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let lit_column = |x: usize| (top..=bottom).any(|y| *grid.get((x, y)));
+    let mut text = String::new();
+    let mut unrecognized = Vec::new();
+    let mut x = 0;
+
+    while x < grid.size.0 {
+        if !lit_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < grid.size.0 && lit_column(x) {
+            x += 1;
+        }
+
+        let glyph = (top..=bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if *grid.get((x, y)) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        match font.iter().find(|(_, pattern)| *pattern == glyph) {
+            Some(&(letter, _)) => text.push(letter),
+            None => {
+                unrecognized.push((text.len(), start));
+                text.push('?');
+            }
+        }
+    }
+
+    if unrecognized.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::UnrecognizedGlyphs(unrecognized))
+    }
+}
+
+pub fn ocr_points<I>(points: I) -> Result<String, OcrError>
+where
+    I: IntoIterator<Item = Coord>,
+{
+    let points: Vec<Coord> = points.into_iter().collect();
+    let size = points.iter().fold((0, 0), |size, &(x, y)| {
+        (size.0.max(x + 1), size.1.max(y + 1))
+    });
+    let mut grid = Grid::new(size, vec![false; size.0 * size.1]);
+
+    for point in points {
+        grid.set(point, true);
+    }
+
+    ocr(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid(lines: &[&str]) -> Grid<bool> {
+        Grid::new(
+            (lines[0].len(), lines.len()),
+            lines
+                .iter()
+                .flat_map(|line| line.chars().map(|char| char == '#'))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_ocr_small_font() {
+        let grid = create_grid(&[
+            "..........................................",
+            ".#....###..####...##.###....##.####.#..#..",
+            ".#....#..#.#.......#.#..#....#.#....#..#..",
+            ".#....#..#.###.....#.###.....#.###..####..",
+            ".#....###..#.......#.#..#....#.#....#..#..",
+            ".#....#.#..#....#..#.#..#.#..#.#....#..#..",
+            ".####.#..#.####..##..###...##..####.#..#..",
+        ]);
+
+        assert_eq!(ocr(&grid), Ok("LREJBJEH".to_string()));
+    }
+
+    #[test]
+    fn test_ocr_large_font() {
+        let grid = create_grid(&[
+            "#....#..######",
+            "#....#..#.....",
+            ".#..#...#.....",
+            ".#..#...#.....",
+            "..##....#####.",
+            "..##....#.....",
+            ".#..#...#.....",
+            ".#..#...#.....",
+            "#....#..#.....",
+            "#....#..######",
+        ]);
+
+        assert_eq!(ocr(&grid), Ok("XE".to_string()));
+    }
+
+    #[test]
+    fn test_ocr_points() {
+        let points = [(0, 0), (0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]
+            .iter()
+            .chain([(1, 5), (2, 5), (3, 5)].iter())
+            .copied();
+
+        assert_eq!(ocr_points(points), Ok("L".to_string()));
+    }
+
+    #[test]
+    fn test_ocr_errors() {
+        let grid = create_grid(&[
+            "####..#...####",
+            "#.....#......#",
+            "###...#.....#.",
+            "#.....#....#..",
+            "#.....#...#...",
+            "####..#...####",
+        ]);
+
+        assert_eq!(
+            ocr(&grid),
+            Err(OcrError::UnrecognizedGlyphs([(1, 6)].to_vec()))
+        );
+        assert_eq!(
+            ocr(&create_grid(&["#", "#", "#"])),
+            Err(OcrError::UnsupportedHeight(3))
+        );
+        assert_eq!(
+            OcrError::UnrecognizedGlyphs([(1, 6), (3, 12)].to_vec()).to_string(),
+            "unrecognized glyphs: #1 at column 6, #3 at column 12"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use rayon::prelude::*;
+
+use crate::{Coord, Grid};
+
+// Rayon counterparts of the iteration helpers, cells are visited in row-major order when collected
+impl<T> Grid<T> {
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (Coord, &T)> + '_
+    where
+        T: Sync,
+    {
+        let size = self.size;
+
+        self.cells
+            .par_iter()
+            .enumerate()
+            .map(move |(index, cell)| (Self::_coord(size, index), cell))
+    }
+
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = (Coord, &mut T)> + '_
+    where
+        T: Send,
+    {
+        let size = self.size;
+
+        self.cells
+            .par_iter_mut()
+            .enumerate()
+            .map(move |(index, cell)| (Self::_coord(size, index), cell))
+    }
+
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = (usize, &mut [T])> + '_
+    where
+        T: Send,
+    {
+        self.cells.par_chunks_mut(self.size.0.max(1)).enumerate()
+    }
+
+    pub fn par_map<U, F>(&self, f: F) -> Grid<U>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(Coord, &T) -> U + Sync + Send,
+    {
+        Grid::new(
+            self.size,
+            self.par_iter()
+                .map(|(coord, cell)| f(coord, cell))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid<usize> {
+        Grid::new((3, 2), [0, 1, 2, 3, 4, 5].to_vec())
+    }
+
+    #[test]
+    fn test_par_iter() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.par_iter().collect::<Vec<(Coord, &usize)>>(),
+            grid.iter().collect::<Vec<(Coord, &usize)>>()
+        );
+        assert_eq!(grid.par_iter().map(|(_, &cell)| cell).sum::<usize>(), 15);
+    }
+
+    #[test]
+    fn test_par_iter_mut() {
+        let mut grid = create_grid();
+
+        grid.par_iter_mut()
+            .for_each(|((x, y), cell)| *cell += x * 10 + y * 100);
+        grid.par_rows_mut().for_each(|(y, row)| row[0] = y + 1000);
+
+        assert_eq!(
+            grid,
+            Grid::new((3, 2), [1000, 11, 22, 1001, 114, 125].to_vec())
+        );
+    }
+
+    #[test]
+    fn test_par_map() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.par_map(|(x, _), &cell| cell * 2 + x),
+            Grid::new((3, 2), [0, 3, 6, 6, 9, 12].to_vec())
+        );
+    }
+}
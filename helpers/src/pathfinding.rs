@@ -0,0 +1,304 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::{Coord, Edges, Grid, Neighborhood, TiledGrid};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub cost: usize,
+    pub coords: Vec<Coord>,
+}
+
+// All searches move between orthogonal neighbors. `passable` is checked on the cell being entered,
+// `cost` receives the cell being left and the cell being entered.
+impl<T> Grid<T> {
+    pub fn bfs<P>(&self, sources: &[Coord], targets: &[Coord], passable: P) -> Option<Path>
+    where
+        P: Fn(Coord, &T) -> bool,
+    {
+        let mut previous: Vec<Option<usize>> = vec![None; self.len()];
+        let mut visited = vec![false; self.len()];
+        let mut queue = VecDeque::new();
+
+        for &source in sources {
+            let index = self.index(source);
+
+            if !visited[index] {
+                visited[index] = true;
+                queue.push_back((source, 0));
+            }
+        }
+
+        while let Some((coord, cost)) = queue.pop_front() {
+            if targets.contains(&coord) {
+                return Some(Path {
+                    cost,
+                    coords: build_path(self.size, &previous, coord),
+                });
+            }
+
+            for neighbor in self.neighbor_coords(coord, Neighborhood::VonNeumann, Edges::Bounded) {
+                let index = self.index(neighbor);
+
+                if !visited[index] && passable(neighbor, self.get(neighbor)) {
+                    visited[index] = true;
+                    previous[index] = Some(self.index(coord));
+                    queue.push_back((neighbor, cost + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn dijkstra<P, C>(
+        &self,
+        sources: &[Coord],
+        targets: &[Coord],
+        passable: P,
+        cost: C,
+    ) -> Option<Path>
+    where
+        P: Fn(Coord, &T) -> bool,
+        C: Fn((Coord, &T), (Coord, &T)) -> usize,
+    {
+        self.astar(sources, targets, passable, cost, |_| 0)
+    }
+
+    // `heuristic` must never overestimate the remaining cost to the closest target
+    pub fn astar<P, C, H>(
+        &self,
+        sources: &[Coord],
+        targets: &[Coord],
+        passable: P,
+        cost: C,
+        heuristic: H,
+    ) -> Option<Path>
+    where
+        P: Fn(Coord, &T) -> bool,
+        C: Fn((Coord, &T), (Coord, &T)) -> usize,
+        H: Fn(Coord) -> usize,
+    {
+        let (passable, cost) = (&passable, &cost);
+
+        search(
+            self.size,
+            sources,
+            targets,
+            move |coord| {
+                let from = (coord, self.get(coord));
+
+                self.neighbors(coord, Neighborhood::VonNeumann, Edges::Bounded)
+                    .filter(move |&(neighbor, value)| passable(neighbor, value))
+                    .map(move |to| (to.0, cost(from, to)))
+            },
+            heuristic,
+        )
+    }
+}
+
+// Same contract as `Grid::dijkstra`, with the transformed values of the tiles
+impl<'a, T, F> TiledGrid<'a, T, F>
+where
+    F: Fn(&T, (usize, usize)) -> T,
+{
+    pub fn dijkstra<P, C>(
+        &self,
+        sources: &[Coord],
+        targets: &[Coord],
+        passable: P,
+        cost: C,
+    ) -> Option<Path>
+    where
+        P: Fn(Coord, &T) -> bool,
+        C: Fn((Coord, &T), (Coord, &T)) -> usize,
+    {
+        let (passable, cost) = (&passable, &cost);
+
+        search(
+            self.size,
+            sources,
+            targets,
+            move |coord| {
+                let value = self.get(coord);
+
+                self.neighbors_iter(coord, false)
+                    .filter(move |(neighbor, to)| passable(*neighbor, to))
+                    .map(move |(neighbor, to)| (neighbor, cost((coord, &value), (neighbor, &to))))
+            },
+            |_| 0,
+        )
+    }
+}
+
+pub(crate) fn search<N, I, H>(
+    size: (usize, usize),
+    sources: &[Coord],
+    targets: &[Coord],
+    edges: N,
+    heuristic: H,
+) -> Option<Path>
+where
+    N: Fn(Coord) -> I,
+    I: IntoIterator<Item = (Coord, usize)>,
+    H: Fn(Coord) -> usize,
+{
+    let index = |coord: Coord| coord.0 + coord.1 * size.0;
+    let mut costs = vec![usize::MAX; size.0 * size.1];
+    let mut previous: Vec<Option<usize>> = vec![None; size.0 * size.1];
+    let mut heap = BinaryHeap::new();
+
+    for &source in sources {
+        assert!(source.0 < size.0);
+        assert!(source.1 < size.1);
+
+        costs[index(source)] = 0;
+        heap.push(Reverse((heuristic(source), 0, source)));
+    }
+
+    while let Some(Reverse((_, cost, coord))) = heap.pop() {
+        if cost > costs[index(coord)] {
+            continue;
+        }
+
+        if targets.contains(&coord) {
+            return Some(Path {
+                cost,
+                coords: build_path(size, &previous, coord),
+            });
+        }
+
+        for (neighbor, step_cost) in edges(coord) {
+            let neighbor_cost = cost + step_cost;
+
+            if neighbor_cost < costs[index(neighbor)] {
+                costs[index(neighbor)] = neighbor_cost;
+                previous[index(neighbor)] = Some(index(coord));
+                heap.push(Reverse((
+                    neighbor_cost + heuristic(neighbor),
+                    neighbor_cost,
+                    neighbor,
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+fn build_path(size: (usize, usize), previous: &[Option<usize>], target: Coord) -> Vec<Coord> {
+    let mut coords = vec![target];
+    let mut current = target.0 + target.1 * size.0;
+
+    while let Some(index) = previous[current] {
+        coords.push((index % size.0, index / size.0));
+        current = index;
+    }
+
+    coords.reverse();
+
+    coords
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid<char> {
+        Grid::new(
+            (5, 4),
+            [
+                '.', '.', '#', '.', '.', //
+                '.', '#', '#', '.', '#', //
+                '.', '.', '.', '.', '.', //
+                '#', '#', '.', '#', '.', //
+            ]
+            .to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = create_grid();
+        let path = grid.bfs(&[(0, 0)], &[(4, 0)], |_, &cell| cell != '#');
+
+        assert_eq!(
+            path,
+            Some(Path {
+                cost: 8,
+                coords: [
+                    (0, 0),
+                    (0, 1),
+                    (0, 2),
+                    (1, 2),
+                    (2, 2),
+                    (3, 2),
+                    (3, 1),
+                    (3, 0),
+                    (4, 0)
+                ]
+                .to_vec()
+            })
+        );
+        assert_eq!(grid.bfs(&[(0, 0)], &[(0, 3)], |_, &cell| cell != '#'), None);
+    }
+
+    #[test]
+    fn test_bfs_multiple_sources_and_targets() {
+        let grid = create_grid();
+        let path = grid.bfs(&[(0, 0), (4, 3)], &[(2, 3), (4, 0)], |_, &cell| cell != '#');
+
+        assert_eq!(
+            path.map(|path| path.coords),
+            Some([(4, 3), (4, 2), (3, 2), (2, 2), (2, 3)].to_vec())
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = Grid::new(
+            (3, 3),
+            [
+                1, 9, 1, //
+                1, 9, 1, //
+                1, 1, 1, //
+            ]
+            .to_vec(),
+        );
+        let path = grid.dijkstra(&[(0, 0)], &[(2, 0)], |_, _| true, |_, (_, &to)| to);
+
+        assert_eq!(
+            path,
+            Some(Path {
+                cost: 6,
+                coords: [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0)].to_vec()
+            })
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = Grid::new((4, 4), [1; 16].to_vec());
+        let path = grid.astar(
+            &[(0, 0)],
+            &[(3, 3)],
+            |_, _| true,
+            |_, (_, &to)| to,
+            |(x, y)| (3 - x) + (3 - y),
+        );
+
+        assert_eq!(
+            path.map(|path| (path.cost, path.coords.len())),
+            Some((6, 7))
+        );
+    }
+
+    #[test]
+    fn test_tiled_grid_dijkstra() {
+        let grid = Grid::new((2, 2), [1, 5, 1, 1].to_vec());
+        let tiled = TiledGrid::new(&grid, (2, 1), |&value, _| value);
+        let path = tiled.dijkstra(&[(0, 0)], &[(2, 0)], |_, _| true, |_, (_, &to)| to);
+
+        assert_eq!(path.map(|path| path.cost), Some(4));
+    }
+}
@@ -0,0 +1,111 @@
+use std::ops::{Add, Sub};
+
+use crate::{Coord, Grid};
+
+// Summed-area table, `table[(x, y)]` holds the sum of every cell above and to the left of
+// `(x, y)` in the source grid, hence the extra row and column of zeroes
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrefixSums<T> {
+    table: Grid<T>,
+}
+
+impl<T> PrefixSums<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(grid: &Grid<T>) -> Self {
+        let mut table = Grid::new(
+            (grid.size.0 + 1, grid.size.1 + 1),
+            vec![T::default(); (grid.size.0 + 1) * (grid.size.1 + 1)],
+        );
+
+        for ((x, y), &cell) in grid.iter() {
+            let sum = cell + *table.get((x, y + 1)) + *table.get((x + 1, y)) - *table.get((x, y));
+            table.set((x + 1, y + 1), sum);
+        }
+
+        PrefixSums { table }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.table.size.0 - 1, self.table.size.1 - 1)
+    }
+
+    // Sum of the `size` rectangle starting at `origin`, in constant time
+    pub fn sum(&self, origin: Coord, size: (usize, usize)) -> T {
+        let (x0, y0) = origin;
+        let (x1, y1) = (origin.0 + size.0, origin.1 + size.1);
+
+        assert!(x1 <= self.size().0);
+        assert!(y1 <= self.size().1);
+
+        // Added before subtracting so unsigned sums never underflow
+        *self.table.get((x1, y1)) + *self.table.get((x0, y0))
+            - *self.table.get((x0, y1))
+            - *self.table.get((x1, y0))
+    }
+
+    pub fn total(&self) -> T {
+        self.sum((0, 0), self.size())
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn prefix_sums(&self) -> PrefixSums<T> {
+        PrefixSums::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid<usize> {
+        Grid::new(
+            (4, 3),
+            [
+                1, 2, 3, 4, //
+                5, 6, 7, 8, //
+                9, 1, 2, 3, //
+            ]
+            .to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_sum() {
+        let sums = create_grid().prefix_sums();
+
+        assert_eq!(sums.size(), (4, 3));
+        assert_eq!(sums.total(), 51);
+        assert_eq!(sums.sum((1, 1), (2, 2)), 16);
+        assert_eq!(sums.sum((3, 0), (1, 3)), 15);
+        assert_eq!(sums.sum((2, 2), (0, 0)), 0);
+    }
+
+    #[test]
+    fn test_sum_matches_brute_force() {
+        let grid = create_grid();
+        let sums = grid.prefix_sums();
+
+        for (origin, _) in grid.iter() {
+            for width in 0..=grid.size.0 - origin.0 {
+                for height in 0..=grid.size.1 - origin.1 {
+                    let expected: usize = grid
+                        .iter()
+                        .filter(|((x, y), _)| {
+                            (origin.0..origin.0 + width).contains(x)
+                                && (origin.1..origin.1 + height).contains(y)
+                        })
+                        .map(|(_, &cell)| cell)
+                        .sum();
+
+                    assert_eq!(sums.sum(origin, (width, height)), expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+
+use crate::{Coord, Edges, Grid, Neighborhood};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    pub label: usize,
+    pub size: usize,
+    // Inclusive `(min, max)` corners
+    pub bounding_box: (Coord, Coord),
+    pub coords: Vec<Coord>,
+}
+
+// Regions are connected through orthogonal neighbors
+impl<T> Grid<T> {
+    pub fn flood_fill<P>(&self, start: Coord, predicate: P) -> Vec<Coord>
+    where
+        P: Fn(Coord, &T) -> bool,
+    {
+        let mut visited = vec![false; self.len()];
+
+        self.fill_from(start, &predicate, &mut visited)
+    }
+
+    // Cells outside every component are labeled 0, components are labeled from 1 in row-major
+    // order of their first cell.
+    pub fn components<P>(&self, predicate: P) -> (Grid<usize>, Vec<Component>)
+    where
+        P: Fn(Coord, &T) -> bool,
+    {
+        let mut visited = vec![false; self.len()];
+        let mut labels = vec![0; self.len()];
+        let mut components: Vec<Component> = Vec::new();
+
+        for index in 0..self.len() {
+            if visited[index] {
+                continue;
+            }
+
+            let coords = self.fill_from(self.coord(index), &predicate, &mut visited);
+
+            if coords.is_empty() {
+                continue;
+            }
+
+            let label = components.len() + 1;
+            let (mut min, mut max) = (coords[0], coords[0]);
+
+            for &coord in coords.iter() {
+                labels[self.index(coord)] = label;
+
+                min = (min.0.min(coord.0), min.1.min(coord.1));
+                max = (max.0.max(coord.0), max.1.max(coord.1));
+            }
+
+            components.push(Component {
+                label,
+                size: coords.len(),
+                bounding_box: (min, max),
+                coords,
+            });
+        }
+
+        (Grid::new(self.size, labels), components)
+    }
+
+    fn fill_from<P>(&self, start: Coord, predicate: &P, visited: &mut [bool]) -> Vec<Coord>
+    where
+        P: Fn(Coord, &T) -> bool,
+    {
+        let start_index = self.index(start);
+
+        if visited[start_index] || !predicate(start, self.get(start)) {
+            return Vec::new();
+        }
+
+        let mut coords = Vec::new();
+        let mut queue = VecDeque::new();
+
+        visited[start_index] = true;
+        queue.push_back(start);
+
+        while let Some(coord) = queue.pop_front() {
+            coords.push(coord);
+
+            for (neighbor, value) in self.neighbors(coord, Neighborhood::VonNeumann, Edges::Bounded)
+            {
+                let index = self.index(neighbor);
+
+                if !visited[index] && predicate(neighbor, value) {
+                    visited[index] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        coords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid<char> {
+        Grid::new(
+            (5, 4),
+            [
+                '#', '#', '.', '.', '#', //
+                '.', '#', '.', '#', '#', //
+                '.', '.', '.', '.', '.', //
+                '#', '.', '#', '#', '.', //
+            ]
+            .to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.flood_fill((0, 0), |_, &cell| cell == '#'),
+            [(0, 0), (1, 0), (1, 1)].to_vec()
+        );
+        assert_eq!(grid.flood_fill((0, 0), |_, &cell| cell == '.'), [].to_vec());
+        assert_eq!(grid.flood_fill((2, 0), |_, &cell| cell == '.').len(), 11);
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = create_grid();
+        let (labels, components) = grid.components(|_, &cell| cell == '#');
+
+        assert_eq!(
+            labels,
+            Grid::new(
+                (5, 4),
+                [
+                    1, 1, 0, 0, 2, //
+                    0, 1, 0, 2, 2, //
+                    0, 0, 0, 0, 0, //
+                    3, 0, 4, 4, 0, //
+                ]
+                .to_vec(),
+            )
+        );
+        assert_eq!(
+            components
+                .iter()
+                .map(|component| (component.label, component.size, component.bounding_box))
+                .collect::<Vec<(usize, usize, (Coord, Coord))>>(),
+            [
+                (1, 3, ((0, 0), (1, 1))),
+                (2, 3, ((3, 0), (4, 1))),
+                (3, 1, ((0, 3), (0, 3))),
+                (4, 2, ((2, 3), (3, 3)))
+            ]
+            .to_vec()
+        );
+        assert_eq!(components[1].coords, [(4, 0), (4, 1), (3, 1)].to_vec());
+    }
+}
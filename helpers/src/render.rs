@@ -0,0 +1,299 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+
+use crate::{Coord, Grid};
+
+pub type Rgb = (u8, u8, u8);
+
+// ANSI 256 color used for highlighted cells
+pub const HIGHLIGHT_COLOR: u8 = 196;
+
+const HIGHLIGHT_RGB: Rgb = (255, 0, 0);
+
+impl<T> Grid<T> {
+    pub fn render_text<F>(&self, highlighted: &[Coord], glyph: F) -> String
+    where
+        F: Fn(&T, bool) -> char,
+    {
+        let highlighted: HashSet<Coord> = highlighted.iter().copied().collect();
+        let mut output = String::with_capacity((self.size.0 + 1) * self.size.1);
+
+        for (coord, cell) in self.iter() {
+            output.push(glyph(cell, highlighted.contains(&coord)));
+
+            if coord.0 + 1 == self.size.0 {
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+
+    // Two spaces per cell with `color` as ANSI 256 background
+    pub fn render_ansi<F>(&self, highlighted: &[Coord], color: F) -> String
+    where
+        F: Fn(&T) -> u8,
+    {
+        let highlighted: HashSet<Coord> = highlighted.iter().copied().collect();
+        let mut output = String::new();
+
+        for (coord, cell) in self.iter() {
+            let color = if highlighted.contains(&coord) {
+                HIGHLIGHT_COLOR
+            } else {
+                color(cell)
+            };
+
+            output.push_str(&format!("\x1b[48;5;{}m  ", color));
+
+            if coord.0 + 1 == self.size.0 {
+                output.push_str("\x1b[0m\n");
+            }
+        }
+
+        output
+    }
+
+    // Grayscale ramp from the lowest (dark) to the highest (light) value
+    pub fn render_heatmap<F>(&self, highlighted: &[Coord], value: F) -> String
+    where
+        F: Fn(&T) -> usize,
+    {
+        let (min, max) = self.value_range(&value);
+
+        self.render_ansi(highlighted, |cell| {
+            232 + scale(value(cell), min, max, 23) as u8
+        })
+    }
+
+    pub fn to_ppm<F>(&self, highlighted: &[Coord], color: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let mut output = format!("P6\n{} {}\n255\n", self.size.0, self.size.1).into_bytes();
+
+        for (_, (r, g, b)) in self.rgb_cells(highlighted, color) {
+            output.extend_from_slice(&[r, g, b]);
+        }
+
+        output
+    }
+
+    pub fn to_png<F>(&self, highlighted: &[Coord], color: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let mut raw = Vec::with_capacity((self.size.0 * 3 + 1) * self.size.1);
+
+        for (coord, (r, g, b)) in self.rgb_cells(highlighted, color) {
+            // Filter type "none" at the start of every scanline
+            if 0 == coord.0 {
+                raw.push(0);
+            }
+
+            raw.extend_from_slice(&[r, g, b]);
+        }
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&(self.size.0 as u32).to_be_bytes());
+        header.extend_from_slice(&(self.size.1 as u32).to_be_bytes());
+        // 8 bit depth, RGB, default compression, filter and interlace methods
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut output = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        png_chunk(&mut output, b"IHDR", &header);
+        png_chunk(&mut output, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut output, b"IEND", &[]);
+
+        output
+    }
+
+    pub fn write_ppm<P, F>(&self, path: P, highlighted: &[Coord], color: F) -> io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+        F: Fn(&T) -> Rgb,
+    {
+        fs::write(path, self.to_ppm(highlighted, color))
+    }
+
+    pub fn write_png<P, F>(&self, path: P, highlighted: &[Coord], color: F) -> io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+        F: Fn(&T) -> Rgb,
+    {
+        fs::write(path, self.to_png(highlighted, color))
+    }
+
+    fn rgb_cells<'a, F>(
+        &'a self,
+        highlighted: &[Coord],
+        color: F,
+    ) -> impl Iterator<Item = (Coord, Rgb)> + 'a
+    where
+        F: Fn(&T) -> Rgb + 'a,
+    {
+        let highlighted: HashSet<Coord> = highlighted.iter().copied().collect();
+
+        self.iter().map(move |(coord, cell)| {
+            if highlighted.contains(&coord) {
+                (coord, HIGHLIGHT_RGB)
+            } else {
+                (coord, color(cell))
+            }
+        })
+    }
+
+    fn value_range<F>(&self, value: &F) -> (usize, usize)
+    where
+        F: Fn(&T) -> usize,
+    {
+        self.iter()
+            .map(|(_, cell)| value(cell))
+            .fold((usize::MAX, usize::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            })
+    }
+}
+
+fn scale(value: usize, min: usize, max: usize, steps: usize) -> usize {
+    if max <= min {
+        return 0;
+    }
+
+    (value - min) * steps / (max - min)
+}
+
+fn png_chunk(output: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    output.extend_from_slice(kind);
+    output.extend_from_slice(data);
+
+    let mut crc_input = kind.to_vec();
+    crc_input.extend_from_slice(data);
+    output.extend_from_slice(&crc32(&crc_input).to_be_bytes());
+}
+
+// Zlib stream made of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut output = vec![0x78, 0x01];
+    let mut chunks = data.chunks(0xffff).peekable();
+
+    if chunks.peek().is_none() {
+        output.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(chunk) = chunks.next() {
+        let last = chunks.peek().is_none();
+        let len = chunk.len() as u16;
+
+        output.push(if last { 1 } else { 0 });
+        output.extend_from_slice(&len.to_le_bytes());
+        output.extend_from_slice(&(!len).to_le_bytes());
+        output.extend_from_slice(chunk);
+    }
+
+    output.extend_from_slice(&adler32(data).to_be_bytes());
+
+    output
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid<usize> {
+        Grid::new((3, 2), [0, 1, 2, 3, 4, 5].to_vec())
+    }
+
+    #[test]
+    fn test_render_text() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.render_text(&[(1, 1)], |&cell, highlighted| {
+                if highlighted {
+                    '*'
+                } else if cell % 2 == 0 {
+                    '#'
+                } else {
+                    '.'
+                }
+            }),
+            "#.#\n.*.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_heatmap() {
+        let grid = Grid::new((2, 1), [1, 9].to_vec());
+
+        assert_eq!(
+            grid.render_heatmap(&[], |&cell| cell),
+            "\x1b[48;5;232m  \x1b[48;5;255m  \x1b[0m\n"
+        );
+        assert_eq!(
+            grid.render_heatmap(&[(1, 0)], |&cell| cell),
+            "\x1b[48;5;232m  \x1b[48;5;196m  \x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let grid = Grid::new((2, 1), [0, 1].to_vec());
+
+        assert_eq!(
+            grid.to_ppm(&[], |&cell| if cell == 1 { (1, 2, 3) } else { (0, 0, 0) }),
+            b"P6\n2 1\n255\n\x00\x00\x00\x01\x02\x03".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_to_png() {
+        let grid = create_grid();
+        let png = grid.to_png(&[], |&cell| (cell as u8, cell as u8, cell as u8));
+
+        assert_eq!(
+            &png[..8],
+            &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']
+        );
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+}
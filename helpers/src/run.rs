@@ -39,6 +39,7 @@ mod tests {
     use std::time::Duration;
 
     #[test]
+    #[allow(clippy::unnecessary_fold)]
     fn test_run() {
         instant::fake::with_fake_elapsed(Duration::from_millis(10));
         let mut output = Vec::new();
@@ -0,0 +1,220 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::{InputError, ParseError};
+
+const PLACEHOLDER: &str = "{}";
+
+// Parses `input` against a pattern with `{}` placeholders, one type per placeholder:
+//
+//     let (x1, y1, x2, y2) = scan!(line, "{},{} -> {},{}", isize, isize, isize, isize)?;
+//     let point = scan!(line, "{},{}", Point { x: isize, y: isize })?;
+//
+// A field runs until the first occurrence of the literal text after it, so placeholders must be
+// separated by some text. The number of placeholders is checked against the types at compile time.
+#[macro_export]
+macro_rules! scan {
+    (@unit $ty:ty) => {
+        ()
+    };
+    ($input:expr, $pattern:literal, $name:ident { $($field:ident : $ty:ty),+ $(,)? }) => {
+        $crate::scan!($input, $pattern, $($ty),+).map(|($($field,)+)| $name { $($field),+ })
+    };
+    ($input:expr, $pattern:literal, $($ty:ty),+ $(,)?) => {{
+        const _: () = assert!(
+            $crate::scan_placeholders($pattern) == [$($crate::scan!(@unit $ty)),+].len(),
+            "scan! pattern and types have a different number of fields"
+        );
+
+        let input: &str = $input;
+
+        $crate::scan_fields(input, $pattern).and_then(|fields| {
+            let mut fields = fields.into_iter();
+
+            Ok(($($crate::scan_parse::<$ty>(input, fields.next().unwrap())?,)+))
+        })
+    }};
+}
+
+#[doc(hidden)]
+pub const fn scan_placeholders(pattern: &str) -> usize {
+    let bytes = pattern.as_bytes();
+    let mut count = 0;
+    let mut index = 0;
+    let mut previous_end = usize::MAX;
+
+    while index + 1 < bytes.len() {
+        if bytes[index] == b'{' && bytes[index + 1] == b'}' {
+            assert!(
+                previous_end != index,
+                "scan! placeholders must be separated by literal text"
+            );
+
+            count += 1;
+            index += 2;
+            previous_end = index;
+        } else {
+            index += 1;
+        }
+    }
+
+    count
+}
+
+#[doc(hidden)]
+pub fn scan_fields<'a>(input: &'a str, pattern: &str) -> Result<Vec<&'a str>, InputError> {
+    let mut literals = pattern.split(PLACEHOLDER);
+    let prefix = literals.next().unwrap_or("");
+
+    if !input.starts_with(prefix) {
+        let matched = input
+            .chars()
+            .zip(prefix.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+
+        return Err(mismatch(input, matched, prefix));
+    }
+
+    let mut position = prefix.len();
+    let mut fields = Vec::new();
+
+    for literal in literals {
+        let rest = &input[position..];
+
+        if literal.is_empty() {
+            fields.push(rest);
+            position = input.len();
+            continue;
+        }
+
+        match rest.find(literal) {
+            Some(index) => {
+                fields.push(&rest[..index]);
+                position += index + literal.len();
+            }
+            None => return Err(mismatch(input, position + diverges(rest, literal), literal)),
+        }
+    }
+
+    if position < input.len() {
+        return Err(InputError::at(
+            input,
+            &input[position..],
+            ParseError::Trailing,
+        ));
+    }
+
+    Ok(fields)
+}
+
+#[doc(hidden)]
+pub fn scan_parse<T>(input: &str, field: &str) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    field
+        .parse()
+        .map_err(|error| InputError::value(input, field, error))
+}
+
+// Where `rest` stops following `literal`: the first occurrence of the longest start of `literal`,
+// or the beginning of `rest` when not even its first character is there
+fn diverges(rest: &str, literal: &str) -> usize {
+    literal
+        .char_indices()
+        .rev()
+        .find_map(|(index, char)| rest.find(&literal[..index + char.len_utf8()]))
+        .unwrap_or(0)
+}
+
+fn mismatch(input: &str, position: usize, literal: &str) -> InputError {
+    InputError::at(
+        input,
+        &input[position..position],
+        ParseError::Mismatch(literal.to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: isize,
+        y: isize,
+    }
+
+    #[test]
+    fn test_scan_tuple() {
+        assert_eq!(
+            scan!("0,9 -> 5,9", "{},{} -> {},{}", usize, usize, usize, usize).unwrap(),
+            (0, 9, 5, 9)
+        );
+        assert_eq!(
+            scan!(
+                "target area: x=20..30, y=-10..-5",
+                "target area: x={}..{}, y={}..{}",
+                isize,
+                isize,
+                isize,
+                isize,
+            )
+            .unwrap(),
+            (20, 30, -10, -5)
+        );
+        assert_eq!(
+            scan!("forward 5", "{} {}", String, usize).unwrap(),
+            ("forward".to_string(), 5)
+        );
+    }
+
+    #[test]
+    fn test_scan_struct() {
+        assert_eq!(
+            scan!("-3,7", "{},{}", Point { x: isize, y: isize }).unwrap(),
+            Point { x: -3, y: 7 }
+        );
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let error = scan!("1,2 => 3,4", "{},{} -> {},{}", u8, u8, u8, u8).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            [
+                "invalid input \"\" at line 1, column 4: expected \" -> \"",
+                "  |",
+                "1 | 1,2 => 3,4",
+                "  |    ^",
+            ]
+            .join("\n")
+        );
+
+        let error = scan!("1 2", "{},{}", u8, u8).unwrap_err();
+        assert_eq!(error.column, 1);
+
+        let error = scan!("target zone: x=1", "target area: x={}", u8).unwrap_err();
+        assert_eq!(error.column, 8);
+
+        let error = scan!("1,x", "{},{}", u8, u8).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "x"));
+        assert_eq!(
+            error.to_string().lines().next(),
+            Some("invalid input \"x\" at line 1, column 3: invalid digit found in string")
+        );
+
+        let error = scan!("move 1 up", "move {}", u8).unwrap_err();
+        assert_eq!(error.text, "1 up");
+
+        let error = scan!("p=1, rest", "p={},", u8).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (5, " rest"));
+    }
+
+    #[test]
+    fn test_scan_placeholders() {
+        assert_eq!(super::scan_placeholders("{},{} -> {},{}"), 4);
+        assert_eq!(super::scan_placeholders("no fields"), 0);
+    }
+}
@@ -0,0 +1,313 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::{try_input_grid, try_input_grid_tokens, Grid, InputError, ParseError};
+
+// Blocks of the input separated by blank lines, every section starts at the beginning of its
+// first line so errors from parsing it can be located in the whole input
+pub struct Sections<'a> {
+    input: &'a str,
+    // First line number of every section and its text
+    sections: Vec<(usize, &'a str)>,
+    next: usize,
+}
+
+impl<'a> Sections<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let mut sections = Vec::new();
+        let mut current: Option<(usize, usize, usize)> = None;
+        let mut offset = 0;
+
+        for (index, line) in input.split('\n').enumerate() {
+            let end = offset + line.trim_end_matches('\r').len();
+
+            if line.trim().is_empty() {
+                if let Some((number, start, end)) = current.take() {
+                    sections.push((number, &input[start..end]));
+                }
+            } else {
+                current = match current {
+                    Some((number, start, _)) => Some((number, start, end)),
+                    None => Some((index + 1, offset, end)),
+                };
+            }
+
+            offset += line.len() + 1;
+        }
+
+        if let Some((number, start, end)) = current {
+            sections.push((number, &input[start..end]));
+        }
+
+        Sections {
+            input,
+            sections,
+            next: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sections.len() - self.next
+    }
+
+    pub fn is_empty(&self) -> bool {
+        0 == self.len()
+    }
+
+    pub fn next_section(&mut self) -> Result<&'a str, InputError> {
+        match self.next() {
+            Some(section) => Ok(section),
+            None => Err(InputError {
+                line: self.input.lines().count() + 1,
+                column: 1,
+                text: String::new(),
+                line_text: String::new(),
+                source: ParseError::MissingSection,
+            }),
+        }
+    }
+
+    // Parses the next section with `parser`, the error is moved to its line in the whole input
+    pub fn next_with<T, P>(&mut self, parser: P) -> Result<T, InputError>
+    where
+        P: FnOnce(&'a str) -> Result<T, InputError>,
+    {
+        let first_line = self.sections.get(self.next).map_or(0, |&(line, _)| line);
+        let section = self.next_section()?;
+
+        parser(section).map_err(|error| InputError {
+            line: error.line + first_line - 1,
+            ..error
+        })
+    }
+
+    pub fn parse<R>(&mut self) -> Result<R, InputError>
+    where
+        R: FromSections<'a>,
+    {
+        R::from_sections(self)
+    }
+
+    // Like `parse`, but fails if any section is left
+    pub fn parse_all<R>(&mut self) -> Result<R, InputError>
+    where
+        R: FromSections<'a>,
+    {
+        let result = self.parse()?;
+
+        match self.next() {
+            Some(section) => Err(InputError::at(
+                self.input,
+                section,
+                ParseError::UnexpectedSection,
+            )),
+            None => Ok(result),
+        }
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let section = self.sections.get(self.next).map(|&(_, section)| section);
+        self.next += 1;
+        self.next = self.next.min(self.sections.len());
+
+        section
+    }
+}
+
+// Types built from one or more sections, tuples take one part after another
+pub trait FromSections<'a>: Sized {
+    fn from_sections(sections: &mut Sections<'a>) -> Result<Self, InputError>;
+}
+
+// The raw text of a single section
+impl<'a> FromSections<'a> for &'a str {
+    fn from_sections(sections: &mut Sections<'a>) -> Result<Self, InputError> {
+        sections.next_section()
+    }
+}
+
+// Values of a single section separated by line breaks or commas
+impl<'a, T> FromSections<'a> for Vec<T>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    fn from_sections(sections: &mut Sections<'a>) -> Result<Self, InputError> {
+        sections.next_with(|section| {
+            section
+                .lines()
+                .flat_map(|line| line.split(','))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|error| InputError::value(section, value, error))
+                })
+                .collect()
+        })
+    }
+}
+
+// A single section as a grid, with whitespace separated cells if any row has whitespace in it
+// and one cell per character otherwise
+impl<'a, T> FromSections<'a> for Grid<T>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    fn from_sections(sections: &mut Sections<'a>) -> Result<Self, InputError> {
+        sections.next_with(|section| {
+            let tokens = section
+                .lines()
+                .any(|line| line.trim().contains(char::is_whitespace));
+
+            if tokens {
+                try_input_grid_tokens(section)
+            } else {
+                try_input_grid(section)
+            }
+        })
+    }
+}
+
+// Every remaining section as a grid
+impl<'a, T> FromSections<'a> for Vec<Grid<T>>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    fn from_sections(sections: &mut Sections<'a>) -> Result<Self, InputError> {
+        let mut grids = Vec::new();
+
+        while !sections.is_empty() {
+            grids.push(Grid::from_sections(sections)?);
+        }
+
+        Ok(grids)
+    }
+}
+
+macro_rules! tuple_from_sections {
+    ($($part:ident),+) => {
+        impl<'a, $($part),+> FromSections<'a> for ($($part,)+)
+        where
+            $($part: FromSections<'a>),+
+        {
+            fn from_sections(
+                sections: &mut Sections<'a>,
+            ) -> Result<Self, InputError> {
+                Ok(($($part::from_sections(sections)?,)+))
+            }
+        }
+    };
+}
+
+tuple_from_sections!(A, B);
+tuple_from_sections!(A, B, C);
+tuple_from_sections!(A, B, C, D);
+
+// Parses the whole input into `R`, usually a tuple with a part per section:
+//
+//     let (numbers, boards): (Vec<usize>, Vec<Grid<usize>>) = sections(input)?;
+pub fn sections<'a, R>(input: &'a str) -> Result<R, InputError>
+where
+    R: FromSections<'a>,
+{
+    Sections::new(input).parse_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+7,4,9,5
+11,17
+
+22 13
+ 8  2
+
+ 3 15
+ 9 18
+";
+
+    #[test]
+    fn test_sections() {
+        let sections = Sections::new("\n\na\nb\n\n\n  c\n\n");
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections.collect::<Vec<&str>>(), ["a\nb", "  c"].to_vec());
+    }
+
+    #[test]
+    fn test_typed_sections() {
+        let (numbers, boards): (Vec<usize>, Vec<Grid<usize>>) = sections(INPUT).unwrap();
+
+        assert_eq!(numbers, [7, 4, 9, 5, 11, 17].to_vec());
+        assert_eq!(
+            boards,
+            [
+                Grid::new((2, 2), [22, 13, 8, 2].to_vec()),
+                Grid::new((2, 2), [3, 15, 9, 18].to_vec()),
+            ]
+            .to_vec()
+        );
+
+        let (template, rules, grid): (&str, &str, Grid<char>) =
+            sections("NNCB\n\nCH -> B\nHH -> N\n\n#.\n.#").unwrap();
+
+        assert_eq!(template, "NNCB");
+        assert_eq!(rules.lines().count(), 2);
+        assert_eq!(grid.size, (2, 2));
+    }
+
+    #[test]
+    fn test_section_errors() {
+        let error = sections::<(Vec<usize>, Grid<usize>)>("1,2\n\n1 2\n3 x").unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (4, 3, "x"));
+        assert_eq!(
+            error.to_string(),
+            [
+                "invalid input \"x\" at line 4, column 3: invalid digit found in string",
+                "  |",
+                "4 | 3 x",
+                "  |   ^",
+            ]
+            .join("\n")
+        );
+
+        let error = sections::<(Vec<usize>, Vec<usize>)>("1,2").unwrap_err();
+        assert!(matches!(error.source, ParseError::MissingSection));
+
+        let error = sections::<(&str, &str)>("a\n\nb\n\nc").unwrap_err();
+        assert!(matches!(error.source, ParseError::UnexpectedSection));
+        assert_eq!((error.line, error.text.as_str()), (5, "c"));
+    }
+
+    #[test]
+    fn test_sections_question_mark() {
+        fn board_sums(input: &str) -> Result<Vec<usize>, Box<dyn Error + Send + Sync>> {
+            let (numbers, boards): (Vec<usize>, Vec<Grid<usize>>) = sections(input)?;
+
+            Ok(boards
+                .iter()
+                .map(|board| board.iter().map(|(_, cell)| cell).sum::<usize>() + numbers.len())
+                .collect())
+        }
+
+        assert_eq!(board_sums(INPUT).unwrap(), [51, 51].to_vec());
+
+        let error = board_sums("1\n\n2 x").unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("invalid input \"x\" at line 3"));
+        assert!(error.source().is_none());
+    }
+}
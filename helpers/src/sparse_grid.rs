@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::iter::FromIterator;
+
+use crate::{Grid, Neighborhood};
+
+pub type SignedCoord = (isize, isize);
+
+#[derive(Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<SignedCoord, T>,
+    bounds: Option<(SignedCoord, SignedCoord)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn from_grid(grid: Grid<T>, fill: T) -> Self
+    where
+        T: PartialEq,
+    {
+        let size = grid.size;
+
+        grid.into_cells()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, cell)| cell != &fill)
+            .map(|(index, cell)| (((index % size.0) as isize, (index / size.0) as isize), cell))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // Inclusive `(min, max)` corners of the coordinates holding a cell
+    pub fn bounds(&self) -> Option<(SignedCoord, SignedCoord)> {
+        self.bounds
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        match self.bounds {
+            Some((min, max)) => ((max.0 - min.0 + 1) as usize, (max.1 - min.1 + 1) as usize),
+            None => (0, 0),
+        }
+    }
+
+    pub fn contains(&self, coord: SignedCoord) -> bool {
+        self.cells.contains_key(&coord)
+    }
+
+    pub fn get(&self, coord: SignedCoord) -> Option<&T> {
+        self.cells.get(&coord)
+    }
+
+    pub fn get_mut(&mut self, coord: SignedCoord) -> Option<&mut T> {
+        self.cells.get_mut(&coord)
+    }
+
+    pub fn insert(&mut self, coord: SignedCoord, value: T) -> Option<T> {
+        self.bounds = Some(grow_bounds(self.bounds, coord));
+        self.cells.insert(coord, value)
+    }
+
+    pub fn remove(&mut self, coord: SignedCoord) -> Option<T> {
+        let value = self.cells.remove(&coord);
+
+        // Only a cell on an edge can hold the bounds where they are
+        if let Some((min, max)) = self.bounds {
+            if value.is_some()
+                && (coord.0 == min.0 || coord.0 == max.0 || coord.1 == min.1 || coord.1 == max.1)
+            {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &coord| Some(grow_bounds(bounds, coord)));
+            }
+        }
+
+        value
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (SignedCoord, &T)> + '_ {
+        let mut coords: Vec<SignedCoord> = self.cells.keys().copied().collect();
+        coords.sort_unstable_by_key(|&(x, y)| (y, x));
+
+        coords
+            .into_iter()
+            .map(move |coord| (coord, self.cells.get(&coord).unwrap()))
+    }
+
+    pub fn neighbors_iter(
+        &self,
+        coord: SignedCoord,
+        with_diagonals: bool,
+    ) -> impl Iterator<Item = (SignedCoord, &T)> + '_ {
+        let neighborhood = Neighborhood::from_diagonals(with_diagonals);
+
+        (0..neighborhood.len())
+            .filter_map(move |index| neighborhood.offset(index))
+            .map(move |(dx, dy)| (coord.0 + dx, coord.1 + dy))
+            .filter_map(move |neighbor| self.get(neighbor).map(|cell| (neighbor, cell)))
+    }
+
+    pub fn to_grid(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (min, _) = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Grid::new((0, 0), Vec::new()),
+        };
+        let size = self.size();
+        let mut cells = vec![fill; size.0 * size.1];
+
+        for (coord, value) in self.cells.iter() {
+            let x = (coord.0 - min.0) as usize;
+            let y = (coord.1 - min.1) as usize;
+            cells[x + y * size.0] = value.clone();
+        }
+
+        Grid::new(size, cells)
+    }
+}
+
+fn grow_bounds(
+    bounds: Option<(SignedCoord, SignedCoord)>,
+    coord: SignedCoord,
+) -> (SignedCoord, SignedCoord) {
+    match bounds {
+        Some((min, max)) => (
+            (min.0.min(coord.0), min.1.min(coord.1)),
+            (max.0.max(coord.0), max.1.max(coord.1)),
+        ),
+        None => (coord, coord),
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+
+        for (coord, cell) in self.iter() {
+            writeln!(f, "{:?}: {:?}", coord, cell)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T> FromIterator<(SignedCoord, T)> for SparseGrid<T> {
+    fn from_iter<K: IntoIterator<Item = (SignedCoord, T)>>(iter: K) -> Self {
+        let mut grid = SparseGrid::new();
+
+        for (coord, value) in iter.into_iter() {
+            grid.insert(coord, value);
+        }
+
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> SparseGrid<char> {
+        [
+            ((-1, -2), 'a'),
+            ((2, -2), 'b'),
+            ((0, 0), 'c'),
+            ((-1, 1), 'd'),
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    }
+
+    #[test]
+    fn test_insert_grows_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((0, 0), 'a');
+        assert_eq!(grid.bounds(), Some(((0, 0), (0, 0))));
+
+        grid.insert((-3, 2), 'b');
+        assert_eq!(grid.bounds(), Some(((-3, 0), (0, 2))));
+
+        grid.insert((1, -1), 'c');
+        assert_eq!(grid.bounds(), Some(((-3, -1), (1, 2))));
+        assert_eq!(grid.size(), (5, 4));
+        assert_eq!(grid.len(), 3);
+    }
+
+    #[test]
+    fn test_remove_shrinks_bounds() {
+        let mut grid = create_grid();
+
+        grid.remove((0, 0));
+        assert_eq!(grid.bounds(), Some(((-1, -2), (2, 1))));
+
+        grid.remove((2, -2));
+        assert_eq!(grid.bounds(), Some(((-1, -2), (-1, 1))));
+        assert_eq!(
+            grid.to_grid('.'),
+            Grid::new((1, 4), ['a', '.', '.', 'd'].to_vec())
+        );
+
+        grid.remove((-1, -2));
+        grid.remove((-1, 1));
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.size(), (0, 0));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = create_grid();
+        assert_eq!(grid.get((-1, -2)), Some(&'a'));
+        assert_eq!(grid.get((1, 1)), None);
+
+        *grid.get_mut((0, 0)).unwrap() = 'x';
+        assert_eq!(grid.get((0, 0)), Some(&'x'));
+    }
+
+    #[test]
+    fn test_iter_row_major() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.iter().collect::<Vec<(SignedCoord, &char)>>(),
+            [
+                ((-1, -2), &'a'),
+                ((2, -2), &'b'),
+                ((0, 0), &'c'),
+                ((-1, 1), &'d')
+            ]
+            .to_vec()
+        );
+    }
+
+    #[test]
+    fn test_neighbors_iter() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.neighbors_iter((0, 0), false)
+                .collect::<Vec<(SignedCoord, &char)>>(),
+            [].to_vec()
+        );
+        assert_eq!(
+            grid.neighbors_iter((0, 0), true)
+                .collect::<Vec<(SignedCoord, &char)>>(),
+            [((-1, 1), &'d')].to_vec()
+        );
+        assert_eq!(
+            grid.neighbors_iter((-1, 0), false)
+                .collect::<Vec<(SignedCoord, &char)>>(),
+            [((0, 0), &'c'), ((-1, 1), &'d')].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.to_grid('.'),
+            Grid::new(
+                (4, 4),
+                [
+                    'a', '.', '.', 'b', //
+                    '.', '.', '.', '.', //
+                    '.', 'c', '.', '.', //
+                    'd', '.', '.', '.', //
+                ]
+                .to_vec(),
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::new((3, 2), ['#', '.', '.', '.', '.', '#'].to_vec());
+
+        assert_eq!(
+            SparseGrid::from_grid(grid, '.'),
+            [((0, 0), '#'), ((2, 1), '#')].iter().cloned().collect()
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{Coord, Edges, Grid, NeighborCoordIter, Neighborhood};
+
+// Read-only view of a grid repeated `tiles.0` times horizontally and `tiles.1` times vertically.
+// Cells are computed on access by passing the original value and its tile position to `transform`.
+pub struct TiledGrid<'a, T, F> {
+    pub size: (usize, usize),
+    grid: &'a Grid<T>,
+    tiles: (usize, usize),
+    transform: F,
+}
+
+impl<'a, T, F> TiledGrid<'a, T, F>
+where
+    F: Fn(&T, (usize, usize)) -> T,
+{
+    pub fn new(grid: &'a Grid<T>, tiles: (usize, usize), transform: F) -> Self {
+        TiledGrid {
+            size: (grid.size.0 * tiles.0, grid.size.1 * tiles.1),
+            grid,
+            tiles,
+            transform,
+        }
+    }
+
+    pub fn tiles(&self) -> (usize, usize) {
+        self.tiles
+    }
+
+    pub fn len(&self) -> usize {
+        self.size.0 * self.size.1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        0 == self.len()
+    }
+
+    pub fn get(&self, coord: Coord) -> T {
+        assert!(coord.0 < self.size.0);
+        assert!(coord.1 < self.size.1);
+
+        let inner = (coord.0 % self.grid.size.0, coord.1 % self.grid.size.1);
+        let tile = (coord.0 / self.grid.size.0, coord.1 / self.grid.size.1);
+
+        (self.transform)(self.grid.get(inner), tile)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, T)> + '_ {
+        (0..self.len()).map(move |index| {
+            let coord = (index % self.size.0, index / self.size.0);
+
+            (coord, self.get(coord))
+        })
+    }
+
+    pub fn neighbors_iter(
+        &self,
+        coord: Coord,
+        with_diagonals: bool,
+    ) -> impl Iterator<Item = (Coord, T)> + '_ {
+        assert!(coord.0 < self.size.0);
+        assert!(coord.1 < self.size.1);
+
+        NeighborCoordIter::new(
+            self.size,
+            coord,
+            Neighborhood::from_diagonals(with_diagonals),
+            Edges::Bounded,
+        )
+        .map(move |coord| (coord, self.get(coord)))
+    }
+
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_fn(self.size, |coord| self.get(coord))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_tiled_grid(
+        grid: &Grid<usize>,
+    ) -> TiledGrid<'_, usize, impl Fn(&usize, (usize, usize)) -> usize> {
+        TiledGrid::new(grid, (3, 2), |&value, (x, y)| value + 10 * (x + y))
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = Grid::new((2, 2), [1, 2, 3, 4].to_vec());
+        let tiled = create_tiled_grid(&grid);
+
+        assert_eq!(tiled.size, (6, 4));
+        assert_eq!(tiled.get((0, 0)), 1);
+        assert_eq!(tiled.get((3, 0)), 12);
+        assert_eq!(tiled.get((5, 3)), 34);
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = Grid::new((2, 2), [1, 2, 3, 4].to_vec());
+        let tiled = create_tiled_grid(&grid);
+
+        assert_eq!(
+            tiled.iter().map(|(_, value)| value).collect::<Vec<usize>>(),
+            [
+                1, 2, 11, 12, 21, 22, //
+                3, 4, 13, 14, 23, 24, //
+                11, 12, 21, 22, 31, 32, //
+                13, 14, 23, 24, 33, 34, //
+            ]
+            .to_vec()
+        );
+    }
+
+    #[test]
+    fn test_neighbors_iter() {
+        let grid = Grid::new((2, 2), [1, 2, 3, 4].to_vec());
+        let tiled = create_tiled_grid(&grid);
+
+        assert_eq!(
+            tiled
+                .neighbors_iter((2, 1), false)
+                .collect::<Vec<(Coord, usize)>>(),
+            [((1, 1), 4), ((2, 0), 11), ((3, 1), 14), ((2, 2), 21)].to_vec()
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{Coord, Grid};
+
+// Borrowed rectangular view into a grid, coordinates are relative to `origin`
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    pub origin: Coord,
+    pub size: (usize, usize),
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.size.0 * self.size.1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        0 == self.len()
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.0 < self.size.0 && coord.1 < self.size.1
+    }
+
+    pub fn get(&self, coord: Coord) -> &'a T {
+        assert!(self.contains(coord));
+
+        self.grid
+            .get((self.origin.0 + coord.0, self.origin.1 + coord.1))
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.size.1);
+
+        &self.grid.row(self.origin.1 + y)[self.origin.0..self.origin.0 + self.size.0]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.size.1).map(move |y| self.row(y))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &'a T)> + '_ {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.grid.subgrid(self.origin, self.size)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn view(&self, origin: Coord, size: (usize, usize)) -> GridView<'_, T> {
+        assert!(origin.0 + size.0 <= self.size.0);
+        assert!(origin.1 + size.1 <= self.size.1);
+
+        GridView {
+            grid: self,
+            origin,
+            size,
+        }
+    }
+
+    // Every `width` x `height` view in row-major order of their origin, like `slice::windows`
+    pub fn windows(
+        &self,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = GridView<'_, T>> + '_ {
+        assert!(width > 0 && height > 0);
+
+        let origins = (
+            (self.size.0 + 1).saturating_sub(width),
+            (self.size.1 + 1).saturating_sub(height),
+        );
+
+        (0..origins.1)
+            .flat_map(move |y| (0..origins.0).map(move |x| self.view((x, y), (width, height))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid<usize> {
+        Grid::new((3, 3), [1, 2, 3, 4, 5, 6, 7, 8, 9].to_vec())
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = create_grid();
+        let view = grid.view((1, 1), (2, 2));
+
+        assert_eq!(view.get((1, 0)), &6);
+        assert_eq!(view.rows().collect::<Vec<&[usize]>>(), [&[5, 6], &[8, 9]]);
+        assert_eq!(
+            view.iter().collect::<Vec<(Coord, &usize)>>(),
+            [((0, 0), &5), ((1, 0), &6), ((0, 1), &8), ((1, 1), &9)].to_vec()
+        );
+        assert_eq!(view.to_grid(), Grid::new((2, 2), [5, 6, 8, 9].to_vec()));
+    }
+
+    #[test]
+    fn test_windows() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.windows(2, 2)
+                .map(|window| window.iter().map(|(_, &cell)| cell).sum())
+                .collect::<Vec<usize>>(),
+            [12, 16, 24, 28].to_vec()
+        );
+        assert_eq!(
+            grid.windows(3, 1)
+                .map(|window| window.origin)
+                .collect::<Vec<Coord>>(),
+            [(0, 0), (0, 1), (0, 2)].to_vec()
+        );
+        assert_eq!(grid.windows(4, 1).count(), 0);
+    }
+}